@@ -0,0 +1,64 @@
+//! Gathering a competition's matches from the API days that have been fetched.
+//!
+//! The API only lists matches by day, so a competition's matchweek or round is put together from
+//! every day that is available.
+
+use std::ops::Deref;
+
+use crate::{
+    schedule,
+    types::{CompetitionId, LiveScoresResponse, Match},
+};
+
+/// Matches from `api_days` in the competition, in kick off order. A match listed on more than one
+/// API day is only included once, from the first day it appears in, so the most up to date
+/// response should come first
+pub fn competition_matches(
+    api_days: impl IntoIterator<Item = impl Deref<Target = LiveScoresResponse>>,
+    competition_id: &CompetitionId,
+) -> Vec<Match> {
+    let mut matches = schedule::merge(api_days, |match_data| match_data.comp.id == *competition_id);
+    matches.sort_by_key(|match_data| match_data.date);
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{build_match_at, kickoff_after},
+        types::Status,
+    };
+
+    fn build(id: &str, competition_id: &str, days: i64) -> Match {
+        let mut match_data = build_match_at(id, kickoff_after(days), 16, Status::Fixture, &[]);
+        match_data.comp.id = CompetitionId::new(competition_id);
+        match_data
+    }
+
+    #[test]
+    fn test_competition_matches() {
+        let mut live = build("today", "league", 0);
+        live.period = 1;
+
+        let api_days = [
+            LiveScoresResponse {
+                matches: vec![live, build("cup tie", "cup", 0)],
+            },
+            LiveScoresResponse {
+                matches: vec![
+                    build("next week", "league", 7),
+                    build("today", "league", 0),
+                    build("last week", "league", -7),
+                ],
+            },
+        ];
+
+        let matches = competition_matches(&api_days, &CompetitionId::new("league"));
+        assert_eq!(
+            matches.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(),
+            ["last week", "today", "next week"]
+        );
+        assert_eq!(matches[1].period, 1);
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use crate::types::{
+    CardEvent, Event, GoalEvent, LiveScoresResponse, Match, MatchId, Period, Score, Status,
+    SubEvent,
+};
+
+/// A change to a single match between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchChange {
+    pub match_id: MatchId,
+    pub kind: ChangeKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    KickOff,
+    Goal(GoalEvent),
+    /// a goal present in the previous snapshot is no longer present, e.g. after a VAR review
+    GoalCancelled(GoalEvent),
+    Card(CardEvent),
+    Substitution(SubEvent),
+    /// any change of period other than kick off and full time
+    PeriodChange {
+        from: Period,
+        to: Period,
+    },
+    Postponed,
+    Suspended,
+    Abandoned,
+    Cancelled,
+    FullTime {
+        score: Option<Score>,
+    },
+}
+
+/// Compares two snapshots and returns the changes for every match present in both.
+///
+/// Matches that only appear in one of the snapshots are ignored as there is nothing to compare
+/// them against.
+pub fn diff_snapshots(
+    previous: &LiveScoresResponse,
+    current: &LiveScoresResponse,
+) -> Vec<MatchChange> {
+    let previous_matches = previous
+        .matches
+        .iter()
+        .map(|m| (&m.id, m))
+        .collect::<HashMap<_, _>>();
+
+    current
+        .matches
+        .iter()
+        .filter_map(|current_match| {
+            previous_matches
+                .get(&current_match.id)
+                .map(|previous_match| (*previous_match, current_match))
+        })
+        .flat_map(|(previous_match, current_match)| {
+            diff_matches(previous_match, current_match)
+                .into_iter()
+                .map(|kind| MatchChange {
+                    match_id: current_match.id.clone(),
+                    kind,
+                })
+        })
+        .collect()
+}
+
+/// Compares two versions of the same match, changes are returned in the order: status and period
+/// changes, new events and finally cancelled goals
+pub fn diff_matches(previous: &Match, current: &Match) -> Vec<ChangeKind> {
+    let mut changes = Vec::new();
+
+    if previous.status != current.status {
+        match current.status {
+            Status::Postponed => changes.push(ChangeKind::Postponed),
+            Status::Suspended => changes.push(ChangeKind::Suspended),
+            Status::Abandoned => changes.push(ChangeKind::Abandoned),
+            Status::Cancelled => changes.push(ChangeKind::Cancelled),
+            _ => (),
+        }
+    }
+
+    let (from, to) = (previous.current_period(), current.current_period());
+    if from != to {
+        let change = match (from, to) {
+            // a slow poll can miss the start of the match entirely
+            (Period::PreMatch, to) if to.is_in_play() => ChangeKind::KickOff,
+            (_, Period::FullTime) => ChangeKind::FullTime {
+                score: current.current_score(),
+            },
+            (from, to) => ChangeKind::PeriodChange { from, to },
+        };
+        changes.push(change);
+    }
+
+    let previous_events = previous.events.as_deref().unwrap_or_default();
+    let current_events = current.events.as_deref().unwrap_or_default();
+
+    let previous_goals = goals(previous_events);
+    let current_goals = goals(current_events);
+    let (previous_paired, current_paired) = pair_goals(&previous_goals, &current_goals);
+
+    let mut current_paired = current_paired.into_iter();
+    for event in current_events {
+        let is_new = match event {
+            Event::Goal(_) => !current_paired.next().unwrap_or_default(),
+            _ => !previous_events
+                .iter()
+                .any(|previous_event| same_event(previous_event, event)),
+        };
+        if !is_new {
+            continue;
+        }
+        match event {
+            Event::Goal(goal_event) => changes.push(ChangeKind::Goal(goal_event.clone())),
+            Event::Card(card_event) => changes.push(ChangeKind::Card(card_event.clone())),
+            Event::Sub(sub_event) => changes.push(ChangeKind::Substitution(sub_event.clone())),
+            Event::Var(_) | Event::Pen(_) => (),
+        }
+    }
+
+    for (goal_event, paired) in previous_goals.into_iter().zip(previous_paired) {
+        if !paired {
+            changes.push(ChangeKind::GoalCancelled(goal_event.clone()));
+        }
+    }
+
+    changes
+}
+
+fn goals(events: &[Event]) -> Vec<&GoalEvent> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Goal(goal_event) => Some(goal_event),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Pairs each goal in `previous` with the same goal in `current`, returning whether each goal in
+/// `previous` and in `current` found a partner.
+///
+/// Goals that are unchanged, minute included, are paired first so a player's other goals stay
+/// paired when one of them is removed. The goals that are left are then paired on period, team
+/// and scorer in order, so a goal whose minute is corrected isn't reported as a new goal.
+fn pair_goals(previous: &[&GoalEvent], current: &[&GoalEvent]) -> (Vec<bool>, Vec<bool>) {
+    let mut previous_paired = vec![false; previous.len()];
+    let mut current_paired = vec![false; current.len()];
+
+    for same_minute in [true, false] {
+        for (previous_goal, previous_paired) in previous.iter().zip(&mut previous_paired) {
+            if *previous_paired {
+                continue;
+            }
+            let partner = current
+                .iter()
+                .zip(&mut current_paired)
+                .find(|(goal, paired)| {
+                    !**paired
+                        && goal.period_id == previous_goal.period_id
+                        && goal.team_id == previous_goal.team_id
+                        && goal.player_id == previous_goal.player_id
+                        && (!same_minute || goal.min == previous_goal.min)
+                });
+            if let Some((_, paired)) = partner {
+                *paired = true;
+                *previous_paired = true;
+            }
+        }
+    }
+
+    (previous_paired, current_paired)
+}
+
+/// Events don't have ids so they are matched on the fields that identify them, this means that
+/// later corrections to details such as the assist provider aren't reported as new events. Goals
+/// are matched with [`pair_goals`] instead
+fn same_event(a: &Event, b: &Event) -> bool {
+    match (a, b) {
+        (Event::Card(a), Event::Card(b)) => {
+            a.period_id == b.period_id
+                && a.min == b.min
+                && a.team_id == b.team_id
+                && a.player_name == b.player_name
+                && a.card_type == b.card_type
+        }
+        (Event::Sub(a), Event::Sub(b)) => {
+            a.period_id == b.period_id
+                && a.min == b.min
+                && a.team_id == b.team_id
+                && a.player_id == b.player_id
+                && a.player2_id == b.player2_id
+        }
+        (a, b) => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{HOME_TEAM_ID, build_goal_event, build_match},
+        types::ScoreKey,
+    };
+
+    #[test]
+    fn test_kick_off() {
+        let previous = build_match(16, Status::Fixture, &[]);
+        let current = build_match(1, Status::Playing, &[(ScoreKey::Total, 0, 0)]);
+        assert_eq!(diff_matches(&previous, &current), vec![ChangeKind::KickOff]);
+    }
+
+    #[test]
+    fn test_goal_and_goal_cancelled() {
+        let goal = build_goal_event(HOME_TEAM_ID, "scorer", 12);
+
+        let previous = build_match(1, Status::Playing, &[(ScoreKey::Total, 0, 0)]);
+        let mut current = build_match(1, Status::Playing, &[(ScoreKey::Total, 1, 0)]);
+        current.events = Some(vec![Event::Goal(goal.clone())]);
+
+        assert_eq!(
+            diff_matches(&previous, &current),
+            vec![ChangeKind::Goal(goal.clone())]
+        );
+        assert_eq!(
+            diff_matches(&current, &previous),
+            vec![ChangeKind::GoalCancelled(goal)]
+        );
+    }
+
+    #[test]
+    fn test_assist_correction_is_not_a_new_goal() {
+        let goal = build_goal_event(HOME_TEAM_ID, "scorer", 12);
+        let mut corrected_goal = goal.clone();
+        corrected_goal.player_2_name = Some("assister".into());
+
+        let mut previous = build_match(1, Status::Playing, &[(ScoreKey::Total, 1, 0)]);
+        previous.events = Some(vec![Event::Goal(goal)]);
+        let mut current = previous.clone();
+        current.events = Some(vec![Event::Goal(corrected_goal)]);
+
+        assert!(diff_matches(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn test_minute_correction_is_not_a_new_goal() {
+        let first = build_goal_event(HOME_TEAM_ID, "scorer", 12);
+        let second = build_goal_event(HOME_TEAM_ID, "scorer", 30);
+        let mut corrected_second = second.clone();
+        corrected_second.min = 31;
+        let third = build_goal_event(HOME_TEAM_ID, "scorer", 40);
+
+        let mut previous = build_match(1, Status::Playing, &[(ScoreKey::Total, 2, 0)]);
+        previous.events = Some(vec![Event::Goal(first.clone()), Event::Goal(second)]);
+        let mut current = build_match(1, Status::Playing, &[(ScoreKey::Total, 3, 0)]);
+        current.events = Some(vec![
+            Event::Goal(first),
+            Event::Goal(corrected_second),
+            Event::Goal(third.clone()),
+        ]);
+
+        assert_eq!(
+            diff_matches(&previous, &current),
+            vec![ChangeKind::Goal(third)]
+        );
+    }
+
+    #[test]
+    fn test_earlier_goal_cancelled() {
+        let first = build_goal_event(HOME_TEAM_ID, "scorer", 12);
+        let second = build_goal_event(HOME_TEAM_ID, "scorer", 30);
+
+        let mut previous = build_match(1, Status::Playing, &[(ScoreKey::Total, 2, 0)]);
+        previous.events = Some(vec![
+            Event::Goal(first.clone()),
+            Event::Goal(second.clone()),
+        ]);
+        let mut current = build_match(1, Status::Playing, &[(ScoreKey::Total, 1, 0)]);
+        current.events = Some(vec![Event::Goal(second)]);
+
+        assert_eq!(
+            diff_matches(&previous, &current),
+            vec![ChangeKind::GoalCancelled(first)]
+        );
+    }
+
+    #[test]
+    fn test_kick_off_missed_by_a_poll() {
+        let previous = build_match(16, Status::Fixture, &[]);
+        let current = build_match(10, Status::Playing, &[(ScoreKey::Total, 0, 0)]);
+        assert_eq!(diff_matches(&previous, &current), vec![ChangeKind::KickOff]);
+    }
+
+    #[test]
+    fn test_full_time_and_postponed() {
+        let previous = build_match(2, Status::Playing, &[(ScoreKey::Total, 2, 1)]);
+        let current = build_match(14, Status::Played, &[(ScoreKey::Total, 2, 1)]);
+        assert_eq!(
+            diff_matches(&previous, &current),
+            vec![ChangeKind::FullTime {
+                score: Some(Score { home: 2, away: 1 })
+            }]
+        );
+
+        let previous = build_match(16, Status::Fixture, &[]);
+        let current = build_match(16, Status::Postponed, &[]);
+        assert_eq!(
+            diff_matches(&previous, &current),
+            vec![ChangeKind::Postponed]
+        );
+    }
+}
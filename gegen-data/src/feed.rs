@@ -0,0 +1,345 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{Receiver, Sender, channel},
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    GegenDataError,
+    diff::{ChangeKind, diff_matches},
+    get_live_scores,
+    types::{CompetitionId, LiveScoresResponse, Match, TeamId},
+};
+
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(6);
+pub const DEFAULT_MAX_CONSECUTIVE_FAILURES: u32 = 3;
+/// upper bound for the back off applied after failed polls
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+const LIVE_FEED_THREAD_NAME: &str = "live feed thread";
+const SLEEP: Duration = Duration::from_millis(100);
+
+/// Selects which matches a subscriber is sent updates for
+#[derive(Debug, Clone, Default)]
+pub enum FeedFilter {
+    #[default]
+    All,
+    Teams(HashSet<TeamId>),
+    Competitions(HashSet<CompetitionId>),
+}
+
+impl FeedFilter {
+    pub fn matches(&self, match_data: &Match) -> bool {
+        match self {
+            FeedFilter::All => true,
+            FeedFilter::Teams(team_ids) => [&match_data.home.id, &match_data.away.id]
+                .into_iter()
+                .flatten()
+                .any(|team_id| team_ids.contains(team_id)),
+            FeedFilter::Competitions(competition_ids) => {
+                competition_ids.contains(&match_data.comp.id)
+            }
+        }
+    }
+}
+
+/// The latest state of a match along with what changed since the previous poll.
+///
+/// The first update for a match has no changes as there is nothing to compare it against.
+#[derive(Debug, Clone)]
+pub struct MatchUpdate {
+    pub match_data: Match,
+    pub changes: Vec<ChangeKind>,
+}
+
+type Callback = Box<dyn FnMut(&MatchUpdate) + Send>;
+
+enum Subscriber {
+    Channel(Sender<MatchUpdate>),
+    Callback(Callback),
+}
+
+struct Subscription {
+    filter: FeedFilter,
+    subscriber: Subscriber,
+}
+
+/// Polls the live scores endpoint and notifies subscribers of updated matches.
+///
+/// The feed can either be driven by the caller with [`LiveFeed::poll_if_due`] or run on its own
+/// thread with [`LiveFeed::spawn`]. After a failed poll the interval is doubled for each
+/// consecutive failure, once `max_consecutive_failures` is reached the feed is considered failed.
+pub struct LiveFeed {
+    client: reqwest::blocking::Client,
+    interval: Duration,
+    max_consecutive_failures: u32,
+    consecutive_failures: u32,
+    last_poll: Option<Instant>,
+    snapshot: Option<LiveScoresResponse>,
+    subscriptions: Vec<Subscription>,
+}
+
+impl LiveFeed {
+    pub fn new(client: reqwest::blocking::Client) -> Self {
+        Self {
+            client,
+            interval: DEFAULT_POLL_INTERVAL,
+            max_consecutive_failures: DEFAULT_MAX_CONSECUTIVE_FAILURES,
+            consecutive_failures: 0,
+            last_poll: None,
+            snapshot: None,
+            subscriptions: Vec::new(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_max_consecutive_failures(mut self, max_consecutive_failures: u32) -> Self {
+        self.max_consecutive_failures = max_consecutive_failures;
+        self
+    }
+
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    /// Subscribe to updates over a channel, the subscription is removed once the receiver is dropped
+    pub fn subscribe(&mut self, filter: FeedFilter) -> Receiver<MatchUpdate> {
+        let (sender, receiver) = channel();
+        self.subscriptions.push(Subscription {
+            filter,
+            subscriber: Subscriber::Channel(sender),
+        });
+        receiver
+    }
+
+    /// Subscribe to updates with a callback, the callback is run on the thread polling the feed
+    pub fn subscribe_with(
+        &mut self,
+        filter: FeedFilter,
+        callback: impl FnMut(&MatchUpdate) + Send + 'static,
+    ) {
+        self.subscriptions.push(Subscription {
+            filter,
+            subscriber: Subscriber::Callback(Box::new(callback)),
+        });
+    }
+
+    /// the most recent successfully fetched live scores
+    pub fn snapshot(&self) -> Option<&LiveScoresResponse> {
+        self.snapshot.as_ref()
+    }
+
+    pub fn has_failed(&self) -> bool {
+        self.consecutive_failures >= self.max_consecutive_failures
+    }
+
+    /// Fetch the live scores now regardless of when they were last fetched
+    pub fn poll(&mut self) -> Result<&LiveScoresResponse, GegenDataError> {
+        self.last_poll = Some(Instant::now());
+
+        let live_scores = match get_live_scores(&self.client) {
+            Ok(live_scores) => live_scores,
+            Err(err) => {
+                self.consecutive_failures += 1;
+                return Err(err);
+            }
+        };
+        self.consecutive_failures = 0;
+
+        self.notify(&live_scores);
+
+        Ok(self.snapshot.insert(live_scores))
+    }
+
+    /// Fetch the live scores if the poll interval, including any back off, has elapsed
+    pub fn poll_if_due(&mut self) -> Result<Option<&LiveScoresResponse>, GegenDataError> {
+        let is_due = match self.last_poll {
+            Some(last_poll) => last_poll.elapsed() >= self.current_delay(),
+            None => true,
+        };
+
+        if is_due {
+            self.poll().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Poll the feed on a background thread until it is stopped or has failed
+    pub fn spawn(mut self) -> LiveFeedHandle {
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let join_handle = std::thread::Builder::new()
+            .name(LIVE_FEED_THREAD_NAME.into())
+            .spawn({
+                let stop = stop.clone();
+                move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        if let Err(err) = self.poll_if_due() {
+                            tracing::error!("failed to poll live feed: {err}");
+                            if self.has_failed() {
+                                return Err(err);
+                            }
+                        }
+                        std::thread::sleep(SLEEP);
+                    }
+                    Ok(())
+                }
+            })
+            .unwrap_or_else(|err| panic!("Failed to run thread {LIVE_FEED_THREAD_NAME}: {err}"));
+
+        LiveFeedHandle { stop, join_handle }
+    }
+
+    fn current_delay(&self) -> Duration {
+        if self.consecutive_failures == 0 {
+            return self.interval;
+        }
+        self.interval
+            .saturating_mul(2_u32.saturating_pow(self.consecutive_failures))
+            .min(MAX_BACKOFF.max(self.interval))
+    }
+
+    fn notify(&mut self, live_scores: &LiveScoresResponse) {
+        if self.subscriptions.is_empty() {
+            return;
+        }
+
+        let previous_matches = self
+            .snapshot
+            .iter()
+            .flat_map(|snapshot| snapshot.matches.iter())
+            .map(|m| (&m.id, m))
+            .collect::<HashMap<_, _>>();
+
+        let updates = live_scores
+            .matches
+            .iter()
+            .filter_map(|current| match previous_matches.get(&current.id) {
+                Some(previous) => {
+                    let changes = diff_matches(previous, current);
+                    (!changes.is_empty() || previous.updated != current.updated).then(|| {
+                        MatchUpdate {
+                            match_data: current.clone(),
+                            changes,
+                        }
+                    })
+                }
+                None => Some(MatchUpdate {
+                    match_data: current.clone(),
+                    changes: Vec::new(),
+                }),
+            })
+            .collect::<Vec<_>>();
+
+        self.subscriptions.retain_mut(|subscription| {
+            for update in updates
+                .iter()
+                .filter(|update| subscription.filter.matches(&update.match_data))
+            {
+                match &mut subscription.subscriber {
+                    Subscriber::Channel(sender) => {
+                        if sender.send(update.clone()).is_err() {
+                            return false;
+                        }
+                    }
+                    Subscriber::Callback(callback) => callback(update),
+                }
+            }
+            true
+        });
+    }
+}
+
+/// Handle to a [`LiveFeed`] running on a background thread
+pub struct LiveFeedHandle {
+    stop: Arc<AtomicBool>,
+    join_handle: JoinHandle<Result<(), GegenDataError>>,
+}
+
+impl LiveFeedHandle {
+    pub fn is_finished(&self) -> bool {
+        self.join_handle.is_finished()
+    }
+
+    /// Stop polling and wait for the feed thread to finish, returns the error that caused the feed
+    /// to fail if it had already stopped, or [`GegenDataError::ThreadPanicked`] if a subscriber's
+    /// callback panicked
+    pub fn stop(self) -> Result<(), GegenDataError> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.join_handle.join().unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(GegenDataError::ThreadPanicked {
+                thread: LIVE_FEED_THREAD_NAME,
+                message,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{HOME_TEAM_ID, build_match},
+        types::Status,
+    };
+
+    #[test]
+    fn test_feed_filter() {
+        let m = build_match(1, Status::Playing, &[]);
+
+        assert!(FeedFilter::All.matches(&m));
+        assert!(FeedFilter::Teams(HashSet::from([TeamId::new(HOME_TEAM_ID)])).matches(&m));
+        assert!(!FeedFilter::Teams(HashSet::from([TeamId::new("other")])).matches(&m));
+        assert!(
+            !FeedFilter::Competitions(HashSet::from([CompetitionId::new("other")])).matches(&m)
+        );
+    }
+
+    #[test]
+    fn test_notify_sends_changed_matches() {
+        let mut feed = LiveFeed::new(reqwest::blocking::Client::new());
+        let receiver = feed.subscribe(FeedFilter::All);
+
+        let kicked_off = build_match(1, Status::Playing, &[]);
+        let mut not_started = kicked_off.clone();
+        not_started.period = 16;
+        not_started.status = Status::Fixture;
+
+        feed.snapshot = Some(LiveScoresResponse {
+            matches: vec![not_started],
+        });
+        feed.notify(&LiveScoresResponse {
+            matches: vec![kicked_off],
+        });
+
+        let update = receiver.try_recv().unwrap();
+        assert_eq!(update.changes, vec![ChangeKind::KickOff]);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_stop_reports_a_panicked_thread() {
+        let handle = LiveFeedHandle {
+            stop: Arc::new(AtomicBool::new(false)),
+            join_handle: std::thread::spawn(|| panic!("subscriber failed")),
+        };
+        assert!(matches!(
+            handle.stop(),
+            Err(GegenDataError::ThreadPanicked { message, .. }) if message == "subscriber failed"
+        ));
+    }
+}
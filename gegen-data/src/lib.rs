@@ -3,6 +3,16 @@ use const_format::concatcp;
 use reqwest::StatusCode;
 use types::LiveScoresResponse;
 
+pub mod competition;
+pub mod diff;
+pub mod feed;
+pub mod schedule;
+pub mod shootout;
+pub mod standings;
+pub mod team;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+pub mod timeline;
 pub mod types;
 mod utils;
 
@@ -90,6 +100,11 @@ pub enum GegenDataError {
     },
     #[error("Failed to deserialise response for {url}: {source:?}")]
     Serialisation { source: reqwest::Error, url: String },
+    #[error("The {thread} panicked: {message}")]
+    ThreadPanicked {
+        thread: &'static str,
+        message: String,
+    },
 }
 
 #[cfg(test)]
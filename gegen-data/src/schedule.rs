@@ -0,0 +1,173 @@
+//! Bucketing matches by when they kick off rather than by the day the API lists them under.
+//!
+//! [`crate::get_matches`] takes a date in the API's own timezone, so a late kick off in the
+//! Americas can be listed under the day after it kicks off for someone in Europe, and an early
+//! kick off in Asia under the day before. A local day can therefore need matches from the API
+//! days either side of it, see [`api_dates_for`].
+//!
+//! The API days are only borrowed, e.g. straight out of whatever map they are kept in, and only
+//! the matches that are returned are cloned.
+
+use std::{collections::HashSet, ops::Deref};
+
+use chrono::{DateTime, Days, NaiveDate, TimeZone, Utc};
+
+use crate::types::{LiveScoresResponse, Match};
+
+/// The API days that can contain matches kicking off on `date` in any timezone, `date` first
+pub fn api_dates_for(date: NaiveDate) -> Vec<NaiveDate> {
+    [
+        Some(date),
+        date.checked_sub_days(Days::new(1)),
+        date.checked_add_days(Days::new(1)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// The API days that can contain matches kicking off between `start` and `end` in any timezone,
+/// earliest first
+pub fn api_dates_between(start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<NaiveDate> {
+    let first = start.date_naive().pred_opt().unwrap_or(start.date_naive());
+    let last = end.date_naive().succ_opt().unwrap_or(end.date_naive());
+    first.iter_days().take_while(|date| *date <= last).collect()
+}
+
+/// Matches from `api_days` that kick off on `date` in `tz`. A match listed on more than one API
+/// day is only included once, from the first day it appears in, so the most up to date response
+/// should come first. Matches keep the order of the API days and of each response
+pub fn matches_on_date<Tz: TimeZone>(
+    api_days: impl IntoIterator<Item = impl Deref<Target = LiveScoresResponse>>,
+    date: NaiveDate,
+    tz: &Tz,
+) -> Vec<Match> {
+    merge(api_days, |match_data| {
+        match_data.date.with_timezone(tz).date_naive() == date
+    })
+}
+
+/// Matches from `api_days` that kick off from `start` up to but not including `end`, see
+/// [`matches_on_date`] for how matches on more than one API day are handled
+pub fn matches_between(
+    api_days: impl IntoIterator<Item = impl Deref<Target = LiveScoresResponse>>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Vec<Match> {
+    merge(api_days, |match_data| {
+        start <= match_data.date && match_data.date < end
+    })
+}
+
+pub(crate) fn merge(
+    api_days: impl IntoIterator<Item = impl Deref<Target = LiveScoresResponse>>,
+    include: impl Fn(&Match) -> bool,
+) -> Vec<Match> {
+    let mut seen = HashSet::new();
+    let mut matches = Vec::new();
+    for api_day in api_days {
+        matches.extend(
+            api_day
+                .matches
+                .iter()
+                .filter(|match_data| include(match_data) && seen.insert(match_data.id.clone()))
+                .cloned(),
+        );
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, TimeZone};
+
+    use super::*;
+    use crate::{test_utils::build_match_at, types::Status};
+
+    fn api_day(matches: Vec<Match>) -> LiveScoresResponse {
+        LiveScoresResponse { matches }
+    }
+
+    #[test]
+    fn test_matches_on_date_merges_adjacent_days() {
+        let date = NaiveDate::from_ymd_opt(2025, 4, 27).unwrap();
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+
+        // 23:00 UTC on the 26th is 01:00 on the 27th in central europe
+        let late_kickoff = build_match_at(
+            "late",
+            Utc.with_ymd_and_hms(2025, 4, 26, 23, 0, 0).unwrap(),
+            16,
+            Status::Fixture,
+            &[],
+        );
+        // 22:30 UTC on the 27th is the 28th in central europe
+        let next_day = build_match_at(
+            "next",
+            Utc.with_ymd_and_hms(2025, 4, 27, 22, 30, 0).unwrap(),
+            16,
+            Status::Fixture,
+            &[],
+        );
+        let afternoon = build_match_at(
+            "afternoon",
+            Utc.with_ymd_and_hms(2025, 4, 27, 13, 0, 0).unwrap(),
+            16,
+            Status::Fixture,
+            &[],
+        );
+
+        let api_days = [
+            api_day(vec![afternoon, next_day]),
+            api_day(vec![late_kickoff]),
+        ];
+
+        let ids = |matches: Vec<Match>| {
+            matches
+                .into_iter()
+                .map(|match_data| match_data.id.as_str().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(matches_on_date(&api_days, date, &cest)),
+            ["afternoon", "late"]
+        );
+        assert_eq!(
+            ids(matches_on_date(&api_days, date, &Utc)),
+            ["afternoon", "next"]
+        );
+    }
+
+    #[test]
+    fn test_matches_listed_twice_are_included_once() {
+        let kickoff = Utc.with_ymd_and_hms(2025, 4, 27, 13, 0, 0).unwrap();
+        let live = build_match_at("m1", kickoff, 1, Status::Fixture, &[]);
+        let stale = build_match_at("m1", kickoff, 16, Status::Fixture, &[]);
+
+        let matches = matches_between(
+            &[api_day(vec![live]), api_day(vec![stale])],
+            kickoff - chrono::Duration::hours(24),
+            kickoff + chrono::Duration::hours(24),
+        );
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].period, 1);
+    }
+
+    #[test]
+    fn test_api_dates() {
+        let date = NaiveDate::from_ymd_opt(2025, 4, 27).unwrap();
+        assert_eq!(
+            api_dates_for(date),
+            [date, date.pred_opt().unwrap(), date.succ_opt().unwrap()]
+        );
+
+        let now = Utc.with_ymd_and_hms(2025, 4, 27, 13, 0, 0).unwrap();
+        let dates = api_dates_between(
+            now - chrono::Duration::hours(24),
+            now + chrono::Duration::hours(24),
+        );
+        assert_eq!(dates.first(), NaiveDate::from_ymd_opt(2025, 4, 25).as_ref());
+        assert_eq!(dates.last(), NaiveDate::from_ymd_opt(2025, 4, 29).as_ref());
+    }
+}
@@ -0,0 +1,209 @@
+use crate::types::{Event, Match, PenaltyEvent, PenaltyOutcome, Period, PlayerId, Score, Side};
+
+/// number of kicks each team takes before the shootout goes to sudden death
+pub const REGULATION_KICKS: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShootoutKick {
+    /// starts at one, rounds after [`REGULATION_KICKS`] are sudden death
+    pub round: usize,
+    pub side: Side,
+    pub player_id: PlayerId,
+    pub player_name: String,
+    pub outcome: PenaltyOutcome,
+    /// the shootout score after this kick
+    pub tally: Score,
+}
+
+impl ShootoutKick {
+    pub fn is_sudden_death(&self) -> bool {
+        self.round > REGULATION_KICKS
+    }
+}
+
+/// The kicks of a penalty shootout in the order they were taken
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Shootout {
+    pub kicks: Vec<ShootoutKick>,
+}
+
+impl Shootout {
+    /// Assembles the shootout from the penalty events of a match, penalties awarded during normal
+    /// or extra time are ignored
+    pub fn from_match(match_data: &Match) -> Option<Self> {
+        let mut penalty_events = match_data
+            .events
+            .iter()
+            .flatten()
+            .filter_map(|event| match event {
+                Event::Pen(penalty_event)
+                    if Period::from(usize::from(penalty_event.period_id)) == Period::Penalties =>
+                {
+                    Some(penalty_event)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if penalty_events.is_empty() {
+            return None;
+        }
+
+        penalty_events.sort_by_key(|penalty_event| penalty_event.pen_num);
+
+        let mut tally = Score::default();
+        let (mut home_taken, mut away_taken) = (0, 0);
+
+        let kicks = penalty_events
+            .into_iter()
+            .map(|penalty_event: &PenaltyEvent| {
+                let side = if match_data.home.id.as_ref() == Some(&penalty_event.team_id) {
+                    Side::Home
+                } else {
+                    Side::Away
+                };
+
+                let taken = match side {
+                    Side::Home => &mut home_taken,
+                    Side::Away => &mut away_taken,
+                };
+                *taken += 1;
+
+                if matches!(penalty_event.outcome, PenaltyOutcome::Scored) {
+                    match side {
+                        Side::Home => tally.home += 1,
+                        Side::Away => tally.away += 1,
+                    }
+                }
+
+                ShootoutKick {
+                    round: *taken,
+                    side,
+                    player_id: penalty_event.player_id.clone(),
+                    player_name: penalty_event.player_name.clone(),
+                    outcome: penalty_event.outcome.clone(),
+                    tally,
+                }
+            })
+            .collect();
+
+        Some(Self { kicks })
+    }
+
+    pub fn score(&self) -> Score {
+        self.kicks.last().map(|kick| kick.tally).unwrap_or_default()
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.kicks.iter().map(|kick| kick.round).max().unwrap_or(0)
+    }
+
+    pub fn is_sudden_death(&self) -> bool {
+        self.rounds() > REGULATION_KICKS
+    }
+
+    pub fn kicks_for(&self, side: Side) -> impl Iterator<Item = &ShootoutKick> {
+        self.kicks.iter().filter(move |kick| kick.side == side)
+    }
+
+    /// The winner once the shootout can no longer be turned around, this can be before all of the
+    /// regulation kicks have been taken
+    pub fn winner(&self) -> Option<Side> {
+        let score = self.score();
+        let home_taken = self.kicks_for(Side::Home).count();
+        let away_taken = self.kicks_for(Side::Away).count();
+
+        if home_taken <= REGULATION_KICKS && away_taken <= REGULATION_KICKS {
+            let home_remaining = REGULATION_KICKS - home_taken;
+            let away_remaining = REGULATION_KICKS - away_taken;
+
+            if usize::from(score.home) > usize::from(score.away) + away_remaining {
+                Some(Side::Home)
+            } else if usize::from(score.away) > usize::from(score.home) + home_remaining {
+                Some(Side::Away)
+            } else {
+                None
+            }
+        } else if home_taken == away_taken {
+            score.leader()
+        } else {
+            None
+        }
+    }
+}
+
+impl Match {
+    /// see [`Shootout::from_match`]
+    pub fn shootout(&self) -> Option<Shootout> {
+        Shootout::from_match(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{AWAY_TEAM_ID, HOME_TEAM_ID, build_match},
+        types::{Status, TeamId},
+    };
+
+    fn build_shootout(outcomes: &[(&str, PenaltyOutcome)]) -> Match {
+        let mut m = build_match(5, Status::Playing, &[]);
+        m.events = Some(
+            outcomes
+                .iter()
+                .enumerate()
+                .map(|(idx, (team_id, outcome))| {
+                    Event::Pen(PenaltyEvent {
+                        period_id: 5,
+                        min: 120,
+                        time_str: None,
+                        team_id: TeamId::new(*team_id),
+                        player_id: PlayerId::new(format!("player {idx}")),
+                        player_name: format!("player {idx}"),
+                        outcome: outcome.clone(),
+                        pen_num: idx + 1,
+                    })
+                })
+                .collect(),
+        );
+        m
+    }
+
+    #[test]
+    fn test_tally_and_early_winner() {
+        let m = build_shootout(&[
+            (HOME_TEAM_ID, PenaltyOutcome::Scored),
+            (AWAY_TEAM_ID, PenaltyOutcome::Saved),
+            (HOME_TEAM_ID, PenaltyOutcome::Scored),
+            (AWAY_TEAM_ID, PenaltyOutcome::Missed),
+            (HOME_TEAM_ID, PenaltyOutcome::Scored),
+            (AWAY_TEAM_ID, PenaltyOutcome::Saved),
+        ]);
+        let shootout = m.shootout().unwrap();
+
+        assert_eq!(shootout.score(), Score { home: 3, away: 0 });
+        assert_eq!(shootout.rounds(), 3);
+        assert_eq!(shootout.winner(), Some(Side::Home));
+        assert!(!shootout.is_sudden_death());
+    }
+
+    #[test]
+    fn test_sudden_death() {
+        let mut outcomes = Vec::new();
+        for _ in 0..REGULATION_KICKS {
+            outcomes.push((HOME_TEAM_ID, PenaltyOutcome::Scored));
+            outcomes.push((AWAY_TEAM_ID, PenaltyOutcome::Scored));
+        }
+        outcomes.push((HOME_TEAM_ID, PenaltyOutcome::Missed));
+
+        let shootout = build_shootout(&outcomes).shootout().unwrap();
+        assert!(shootout.is_sudden_death());
+        assert_eq!(shootout.winner(), None);
+
+        outcomes.push((AWAY_TEAM_ID, PenaltyOutcome::Scored));
+        let shootout = build_shootout(&outcomes).shootout().unwrap();
+        assert_eq!(shootout.winner(), Some(Side::Away));
+        assert_eq!(shootout.score(), Score { home: 5, away: 6 });
+    }
+}
@@ -0,0 +1,326 @@
+//! League tables computed from results.
+//!
+//! The API doesn't provide standings, so they are worked out from whichever of a competition's
+//! results are available. Teams are ranked on points, then on the [`Tiebreaker`]s in order and
+//! finally by name so the order is stable.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use serde::Deserialize;
+
+use crate::types::{Match, MatchResult, Score, Side, TeamId};
+
+/// number of results kept in [`Standing::form`]
+pub const FORM_LENGTH: usize = 5;
+
+/// Ways of separating teams that are level on points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tiebreaker {
+    GoalDifference,
+    GoalsFor,
+    Wins,
+    /// points in the matches between the teams level on points
+    HeadToHeadPoints,
+    /// goal difference in the matches between the teams level on points
+    HeadToHeadGoalDifference,
+}
+
+/// goal difference then goals scored, as used by most leagues
+pub const DEFAULT_TIEBREAKERS: [Tiebreaker; 2] = [Tiebreaker::GoalDifference, Tiebreaker::GoalsFor];
+
+/// One team's row in a league table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub team_id: TeamId,
+    pub team_name: String,
+    pub played: u16,
+    pub won: u16,
+    pub drawn: u16,
+    pub lost: u16,
+    pub goals_for: u16,
+    pub goals_against: u16,
+    /// the results of the last [`FORM_LENGTH`] matches, most recent first
+    pub form: Vec<MatchResult>,
+}
+
+impl Standing {
+    fn new(team_id: TeamId, team_name: String) -> Self {
+        Self {
+            team_id,
+            team_name,
+            played: 0,
+            won: 0,
+            drawn: 0,
+            lost: 0,
+            goals_for: 0,
+            goals_against: 0,
+            form: Vec::new(),
+        }
+    }
+
+    /// three points for a win and one for a draw
+    pub fn points(&self) -> u16 {
+        self.won * 3 + self.drawn
+    }
+
+    pub fn goal_difference(&self) -> i32 {
+        i32::from(self.goals_for) - i32::from(self.goals_against)
+    }
+
+    fn record(&mut self, scored: u8, conceded: u8) {
+        self.played += 1;
+        self.goals_for += u16::from(scored);
+        self.goals_against += u16::from(conceded);
+        let result = match scored.cmp(&conceded) {
+            Ordering::Greater => MatchResult::Win,
+            Ordering::Equal => MatchResult::Draw,
+            Ordering::Less => MatchResult::Loss,
+        };
+        match result {
+            MatchResult::Win => self.won += 1,
+            MatchResult::Draw => self.drawn += 1,
+            MatchResult::Loss => self.lost += 1,
+        }
+        if self.form.len() < FORM_LENGTH {
+            self.form.push(result);
+        }
+    }
+}
+
+/// A finished match between two known teams
+struct PlayedMatch<'a> {
+    home: &'a TeamId,
+    away: &'a TeamId,
+    score: Score,
+}
+
+/// Points and goal difference in the matches between teams that are level on points
+#[derive(Default, Clone, Copy)]
+struct HeadToHead {
+    points: u16,
+    goal_difference: i32,
+}
+
+/// The league table for `matches`, which should all be from one competition and not contain
+/// duplicates.
+///
+/// Only finished matches between two known teams count, postponed, abandoned and cancelled
+/// matches are left out. Extra time goals count but penalty shootouts don't, so cup ties settled
+/// on penalties are draws.
+pub fn league_table<'a>(
+    matches: impl IntoIterator<Item = &'a Match>,
+    tiebreakers: &[Tiebreaker],
+) -> Vec<Standing> {
+    let mut played = matches
+        .into_iter()
+        .filter(|match_data| match_data.is_finished() && !match_data.status.is_disrupted())
+        .filter_map(|match_data| {
+            Some((
+                match_data,
+                PlayedMatch {
+                    home: match_data.home.id.as_ref()?,
+                    away: match_data.away.id.as_ref()?,
+                    score: match_data.current_score()?,
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+    // most recent first so the form is in the right order
+    played.sort_by_key(|(match_data, _)| std::cmp::Reverse(match_data.date));
+
+    let mut standings: HashMap<&TeamId, Standing> = HashMap::new();
+    for (match_data, result) in &played {
+        for side in [Side::Home, Side::Away] {
+            let (team_id, team) = match side {
+                Side::Home => (result.home, &match_data.home),
+                Side::Away => (result.away, &match_data.away),
+            };
+            standings
+                .entry(team_id)
+                .or_insert_with(|| {
+                    Standing::new(team_id.clone(), team.name.clone().unwrap_or_default())
+                })
+                .record(
+                    result.score.for_side(side),
+                    result.score.for_side(side.opposite()),
+                );
+        }
+    }
+
+    let results = played
+        .into_iter()
+        .map(|(_, result)| result)
+        .collect::<Vec<_>>();
+    let head_to_head = head_to_head(&standings, &results);
+
+    let mut table = standings.into_values().collect::<Vec<_>>();
+    table.sort_by(|a, b| {
+        b.points()
+            .cmp(&a.points())
+            .then_with(|| {
+                tiebreakers
+                    .iter()
+                    .fold(Ordering::Equal, |ordering, tiebreaker| {
+                        ordering.then_with(|| compare(*tiebreaker, a, b, &head_to_head))
+                    })
+            })
+            .then_with(|| a.team_name.cmp(&b.team_name))
+    });
+    table
+}
+
+/// compares two teams on one tiebreaker, the team that should be higher in the table is less
+fn compare(
+    tiebreaker: Tiebreaker,
+    a: &Standing,
+    b: &Standing,
+    head_to_head: &HashMap<&TeamId, HeadToHead>,
+) -> Ordering {
+    let h2h = |standing: &Standing| {
+        head_to_head
+            .get(&standing.team_id)
+            .copied()
+            .unwrap_or_default()
+    };
+    match tiebreaker {
+        Tiebreaker::GoalDifference => b.goal_difference().cmp(&a.goal_difference()),
+        Tiebreaker::GoalsFor => b.goals_for.cmp(&a.goals_for),
+        Tiebreaker::Wins => b.won.cmp(&a.won),
+        Tiebreaker::HeadToHeadPoints => h2h(b).points.cmp(&h2h(a).points),
+        Tiebreaker::HeadToHeadGoalDifference => h2h(b).goal_difference.cmp(&h2h(a).goal_difference),
+    }
+}
+
+/// each team's record in the matches against the other teams on the same points
+fn head_to_head<'a>(
+    standings: &HashMap<&'a TeamId, Standing>,
+    results: &[PlayedMatch<'a>],
+) -> HashMap<&'a TeamId, HeadToHead> {
+    let points = |team_id: &TeamId| standings.get(team_id).map(Standing::points);
+    let mut head_to_head: HashMap<&TeamId, HeadToHead> = HashMap::new();
+
+    for result in results {
+        if points(result.home) != points(result.away) {
+            continue;
+        }
+        for (team_id, side) in [(result.home, Side::Home), (result.away, Side::Away)] {
+            let scored = result.score.for_side(side);
+            let conceded = result.score.for_side(side.opposite());
+            let entry = head_to_head.entry(team_id).or_default();
+            entry.goal_difference += i32::from(scored) - i32::from(conceded);
+            entry.points += match scored.cmp(&conceded) {
+                Ordering::Greater => 3,
+                Ordering::Equal => 1,
+                Ordering::Less => 0,
+            };
+        }
+    }
+    head_to_head
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{build_match_at, kickoff_after},
+        types::{ScoreKey, Status, Team},
+    };
+
+    fn result(days: i64, home: &str, away: &str, score: (u8, u8)) -> Match {
+        let mut match_data = build_match_at(
+            &format!("{home} v {away} {days}"),
+            kickoff_after(days),
+            14,
+            Status::Played,
+            &[(ScoreKey::Ft, score.0, score.1)],
+        );
+        let team = |name: &str| Team {
+            id: Some(TeamId::new(name)),
+            name: Some(name.to_string()),
+        };
+        match_data.home = team(home);
+        match_data.away = team(away);
+        match_data
+    }
+
+    fn order(table: &[Standing]) -> Vec<&str> {
+        table
+            .iter()
+            .map(|standing| standing.team_name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_league_table() {
+        let mut postponed = result(3, "a", "b", (0, 0));
+        postponed.status = Status::Postponed;
+        let mut fixture = result(4, "c", "d", (0, 0));
+        fixture.period = 16;
+        fixture.status = Status::Fixture;
+
+        let matches = [
+            result(0, "a", "b", (2, 0)),
+            result(1, "c", "a", (1, 1)),
+            result(2, "b", "c", (3, 1)),
+            postponed,
+            fixture,
+        ];
+        let table = league_table(&matches, &DEFAULT_TIEBREAKERS);
+
+        assert_eq!(order(&table), ["a", "b", "c"]);
+        let a = &table[0];
+        assert_eq!(
+            (
+                a.played,
+                a.won,
+                a.drawn,
+                a.lost,
+                a.goals_for,
+                a.goals_against
+            ),
+            (2, 1, 1, 0, 3, 1)
+        );
+        assert_eq!(a.points(), 4);
+        assert_eq!(a.form, [MatchResult::Draw, MatchResult::Win]);
+        assert_eq!(table[1].goal_difference(), 0);
+        assert_eq!(table[2].points(), 1);
+    }
+
+    #[test]
+    fn test_tiebreakers() {
+        // a and b both win once and lose once, b has the better goal difference but lost to a
+        let matches = [
+            result(0, "a", "b", (1, 0)),
+            result(1, "b", "c", (5, 0)),
+            result(2, "c", "a", (1, 0)),
+        ];
+
+        let table = league_table(&matches, &DEFAULT_TIEBREAKERS);
+        assert_eq!(order(&table), ["b", "a", "c"]);
+
+        let table = league_table(
+            &matches,
+            &[Tiebreaker::HeadToHeadPoints, Tiebreaker::GoalDifference],
+        );
+        // all three are level on points and beat each other once, so head to head can't separate
+        // them and goal difference decides
+        assert_eq!(order(&table), ["b", "a", "c"]);
+
+        let matches = [
+            result(0, "a", "b", (1, 0)),
+            result(1, "b", "c", (5, 0)),
+            result(2, "a", "c", (0, 0)),
+            result(3, "b", "c", (0, 0)),
+        ];
+        // a and b are level on four points, b has the better goal difference but lost to a
+        assert_eq!(
+            order(&league_table(&matches, &DEFAULT_TIEBREAKERS)),
+            ["b", "a", "c"]
+        );
+        assert_eq!(
+            order(&league_table(&matches, &[Tiebreaker::HeadToHeadPoints])),
+            ["a", "b", "c"]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Everything known about one team, gathered from the API days that have been fetched.
+//!
+//! [`Team`](crate::types::Team) is only an id and a name inside each [`Match`], there's no
+//! endpoint for a team's matches so they are collected from whichever days are available.
+
+use std::{cmp::Reverse, ops::Deref};
+
+use crate::{
+    schedule,
+    types::{LiveScoresResponse, Match, MatchResult, TeamId},
+};
+
+/// A team's matches split into those that have been played and those still to come
+#[derive(Debug, Clone, Default)]
+pub struct TeamMatches {
+    /// finished matches, most recent first
+    pub results: Vec<Match>,
+    /// matches in play, yet to start or that won't be played as scheduled, soonest first
+    pub fixtures: Vec<Match>,
+}
+
+impl TeamMatches {
+    /// The results of the last `count` matches for the team, most recent first
+    pub fn form(&self, team_id: &TeamId, count: usize) -> Vec<MatchResult> {
+        self.results
+            .iter()
+            .filter_map(|match_data| match_data.result_for(team_id))
+            .take(count)
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty() && self.fixtures.is_empty()
+    }
+}
+
+/// Matches from `api_days` involving `team_id`. A match listed on more than one API day is only
+/// included once, from the first day it appears in, so the most up to date response should come
+/// first
+pub fn team_matches(
+    api_days: impl IntoIterator<Item = impl Deref<Target = LiveScoresResponse>>,
+    team_id: &TeamId,
+) -> TeamMatches {
+    let (mut results, mut fixtures): (Vec<_>, Vec<_>) =
+        schedule::merge(api_days, |match_data| match_data.side_of(team_id).is_some())
+            .into_iter()
+            .partition(|match_data| match_data.is_finished() && !match_data.status.is_disrupted());
+
+    results.sort_by_key(|match_data| Reverse(match_data.date));
+    fixtures.sort_by_key(|match_data| match_data.date);
+
+    TeamMatches { results, fixtures }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{AWAY_TEAM_ID, HOME_TEAM_ID, build_match_at, kickoff_after},
+        types::{ScoreKey, Status},
+    };
+
+    fn ids(matches: &[Match]) -> Vec<&str> {
+        matches.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_team_matches_splits_results_and_fixtures() {
+        let mut other_teams = build_match_at(
+            "other",
+            kickoff_after(0),
+            14,
+            Status::Played,
+            &[(ScoreKey::Total, 1, 0)],
+        );
+        other_teams.home.id = Some(TeamId::new("other home"));
+        other_teams.away.id = Some(TeamId::new("other away"));
+
+        let api_days = [
+            LiveScoresResponse {
+                matches: vec![
+                    build_match_at(
+                        "live",
+                        kickoff_after(0),
+                        1,
+                        Status::Playing,
+                        &[(ScoreKey::Total, 1, 0)],
+                    ),
+                    other_teams,
+                    build_match_at(
+                        "older",
+                        kickoff_after(-7),
+                        14,
+                        Status::Played,
+                        &[(ScoreKey::Total, 0, 2)],
+                    ),
+                ],
+            },
+            LiveScoresResponse {
+                matches: vec![
+                    build_match_at("later", kickoff_after(7), 16, Status::Fixture, &[]),
+                    build_match_at("postponed", kickoff_after(3), 16, Status::Postponed, &[]),
+                    build_match_at(
+                        "recent",
+                        kickoff_after(-3),
+                        14,
+                        Status::Played,
+                        &[(ScoreKey::Total, 1, 1)],
+                    ),
+                    // a stale copy of the live match
+                    build_match_at("live", kickoff_after(0), 16, Status::Fixture, &[]),
+                ],
+            },
+        ];
+
+        let team_matches = team_matches(&api_days, &TeamId::new(HOME_TEAM_ID));
+        assert_eq!(ids(&team_matches.results), ["recent", "older"]);
+        assert_eq!(ids(&team_matches.fixtures), ["live", "postponed", "later"]);
+        assert_eq!(team_matches.fixtures[0].period, 1);
+    }
+
+    #[test]
+    fn test_form() {
+        let api_days = [LiveScoresResponse {
+            matches: vec![
+                build_match_at(
+                    "win",
+                    kickoff_after(-1),
+                    14,
+                    Status::Played,
+                    &[(ScoreKey::Total, 2, 1)],
+                ),
+                build_match_at(
+                    "draw",
+                    kickoff_after(-2),
+                    14,
+                    Status::Played,
+                    &[(ScoreKey::Total, 0, 0)],
+                ),
+                build_match_at(
+                    "loss",
+                    kickoff_after(-3),
+                    14,
+                    Status::Played,
+                    &[(ScoreKey::Total, 0, 3)],
+                ),
+            ],
+        }];
+
+        let team_matches = team_matches(&api_days, &TeamId::new(AWAY_TEAM_ID));
+        assert_eq!(
+            team_matches.form(&TeamId::new(AWAY_TEAM_ID), 2),
+            [MatchResult::Loss, MatchResult::Draw]
+        );
+        assert_eq!(
+            team_matches.form(&TeamId::new(HOME_TEAM_ID), 5),
+            [MatchResult::Win, MatchResult::Draw, MatchResult::Loss]
+        );
+    }
+}
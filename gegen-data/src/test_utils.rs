@@ -0,0 +1,89 @@
+//! Helpers for building matches in tests, other crates can use them with the `test-utils`
+//! feature
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::types::{
+    Competition, CompetitionId, Country, GoalEvent, GoalType, Match, MatchId, PlayerId, Score,
+    ScoreKey, Status, Team, TeamId,
+};
+
+pub const HOME_TEAM_ID: &str = "home";
+pub const AWAY_TEAM_ID: &str = "away";
+
+pub fn build_match(period: usize, status: Status, scores: &[(ScoreKey, u8, u8)]) -> Match {
+    let score = scores
+        .iter()
+        .map(|(key, home, away)| {
+            (
+                *key,
+                Score {
+                    home: *home,
+                    away: *away,
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    Match {
+        id: MatchId::new("match"),
+        period,
+        time: None,
+        status,
+        comp: Competition {
+            id: CompetitionId::new("competition"),
+            name: "Premier League".into(),
+            country: Country {
+                id: "england".into(),
+                full_name: "England".into(),
+            },
+        },
+        date: kickoff_after(0),
+        home: Team {
+            id: Some(TeamId::new(HOME_TEAM_ID)),
+            name: Some("Home".into()),
+        },
+        away: Team {
+            id: Some(TeamId::new(AWAY_TEAM_ID)),
+            name: Some("Away".into()),
+        },
+        score: (!score.is_empty()).then_some(score),
+        events: None,
+        updated: Utc.with_ymd_and_hms(2025, 4, 27, 15, 0, 0).unwrap(),
+    }
+}
+
+/// [`build_match`] with its own id and kick off time, for tests that need more than one match
+pub fn build_match_at(
+    id: &str,
+    kickoff: DateTime<Utc>,
+    period: usize,
+    status: Status,
+    scores: &[(ScoreKey, u8, u8)],
+) -> Match {
+    let mut match_data = build_match(period, status, scores);
+    match_data.id = MatchId::new(id);
+    match_data.date = kickoff;
+    match_data
+}
+
+/// the kick off time of [`build_match`] moved by `days`
+pub fn kickoff_after(days: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 4, 27, 15, 0, 0).unwrap() + Duration::days(days)
+}
+
+pub fn build_goal_event(team_id: &str, player_name: &str, min: u16) -> GoalEvent {
+    GoalEvent {
+        period_id: if min > 45 { 2 } else { 1 },
+        min,
+        time_str: Some(format!("{min}'")),
+        team_id: TeamId::new(team_id),
+        player_id: PlayerId::new(player_name),
+        player_name: player_name.into(),
+        player_2_name: None,
+        goal_type: GoalType::Goal,
+        score: None,
+    }
+}
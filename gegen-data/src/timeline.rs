@@ -0,0 +1,227 @@
+use std::fmt;
+
+use crate::types::{
+    CardEvent, Event, GoalEvent, Match, PenaltyEvent, Period, PlayerId, SubEvent, TeamId, VAREvent,
+};
+
+/// The time an event happened at, e.g. `45+2'` is minute 45 with two minutes of added time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MatchTime {
+    pub minute: u16,
+    pub added: Option<u16>,
+}
+
+impl MatchTime {
+    /// parses times in the format used by the data provider, e.g. `"12'"` or `"90+4'"`
+    pub fn parse(time_str: &str) -> Option<Self> {
+        let time_str = time_str.trim().trim_end_matches('\'');
+        let (minute, added) = match time_str.split_once('+') {
+            Some((minute, added)) => (minute, Some(added.trim().parse().ok()?)),
+            None => (time_str, None),
+        };
+        Some(Self {
+            minute: minute.trim().parse().ok()?,
+            added,
+        })
+    }
+}
+
+impl fmt::Display for MatchTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.added {
+            Some(added) => write!(f, "{}+{added}'", self.minute),
+            None => write!(f, "{}'", self.minute),
+        }
+    }
+}
+
+/// Fields shared by every kind of match event
+pub trait TimelineEvent {
+    fn period_id(&self) -> u8;
+    fn minute(&self) -> u16;
+    fn time_str(&self) -> Option<&str>;
+    fn team_id(&self) -> &TeamId;
+    fn player_id(&self) -> Option<&PlayerId>;
+    fn player_name(&self) -> Option<&str>;
+
+    fn period(&self) -> Period {
+        Period::from(usize::from(self.period_id()))
+    }
+
+    /// the parsed time string, falling back to the minute if it's missing or malformed
+    fn match_time(&self) -> MatchTime {
+        self.time_str()
+            .and_then(MatchTime::parse)
+            .unwrap_or(MatchTime {
+                minute: self.minute(),
+                added: None,
+            })
+    }
+
+    fn added_time(&self) -> Option<u16> {
+        self.match_time().added
+    }
+}
+
+/// implements [`TimelineEvent`] for events with a required player
+macro_rules! impl_timeline_event {
+    ($($event:ty),*) => {
+        $(
+            impl TimelineEvent for $event {
+                fn period_id(&self) -> u8 {
+                    self.period_id
+                }
+
+                fn minute(&self) -> u16 {
+                    self.min
+                }
+
+                fn time_str(&self) -> Option<&str> {
+                    self.time_str.as_deref()
+                }
+
+                fn team_id(&self) -> &TeamId {
+                    &self.team_id
+                }
+
+                fn player_id(&self) -> Option<&PlayerId> {
+                    Some(&self.player_id)
+                }
+
+                fn player_name(&self) -> Option<&str> {
+                    Some(&self.player_name)
+                }
+            }
+        )*
+    };
+}
+
+impl_timeline_event!(GoalEvent, SubEvent, VAREvent, PenaltyEvent);
+
+impl TimelineEvent for CardEvent {
+    fn period_id(&self) -> u8 {
+        self.period_id
+    }
+
+    fn minute(&self) -> u16 {
+        self.min
+    }
+
+    fn time_str(&self) -> Option<&str> {
+        self.time_str.as_deref()
+    }
+
+    fn team_id(&self) -> &TeamId {
+        &self.team_id
+    }
+
+    fn player_id(&self) -> Option<&PlayerId> {
+        None
+    }
+
+    fn player_name(&self) -> Option<&str> {
+        self.player_name.as_deref()
+    }
+}
+
+impl Event {
+    fn as_timeline_event(&self) -> &dyn TimelineEvent {
+        match self {
+            Event::Sub(sub_event) => sub_event,
+            Event::Goal(goal_event) => goal_event,
+            Event::Card(card_event) => card_event,
+            Event::Var(var_event) => var_event,
+            Event::Pen(penalty_event) => penalty_event,
+        }
+    }
+}
+
+impl TimelineEvent for Event {
+    fn period_id(&self) -> u8 {
+        self.as_timeline_event().period_id()
+    }
+
+    fn minute(&self) -> u16 {
+        self.as_timeline_event().minute()
+    }
+
+    fn time_str(&self) -> Option<&str> {
+        self.as_timeline_event().time_str()
+    }
+
+    fn team_id(&self) -> &TeamId {
+        self.as_timeline_event().team_id()
+    }
+
+    fn player_id(&self) -> Option<&PlayerId> {
+        self.as_timeline_event().player_id()
+    }
+
+    fn player_name(&self) -> Option<&str> {
+        self.as_timeline_event().player_name()
+    }
+}
+
+/// Orders events chronologically by period, minute and added time.
+///
+/// Events at the same time keep the order they were given in.
+pub fn timeline(events: &[Event]) -> Vec<&Event> {
+    let mut timeline = events.iter().collect::<Vec<_>>();
+    timeline.sort_by_key(|event| (event.period_id(), event.match_time()));
+    timeline
+}
+
+impl Match {
+    /// see [`timeline`]
+    pub fn timeline(&self) -> Vec<&Event> {
+        self.events.as_deref().map(timeline).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{HOME_TEAM_ID, build_goal_event};
+
+    #[test]
+    fn test_parse_match_time() {
+        assert_eq!(
+            MatchTime::parse("45+2'"),
+            Some(MatchTime {
+                minute: 45,
+                added: Some(2)
+            })
+        );
+        assert_eq!(
+            MatchTime::parse("90'"),
+            Some(MatchTime {
+                minute: 90,
+                added: None
+            })
+        );
+        assert_eq!(MatchTime::parse("ht"), None);
+        assert_eq!(MatchTime::parse("45+2'").unwrap().to_string(), "45+2'");
+    }
+
+    #[test]
+    fn test_timeline_ordering() {
+        let mut stoppage_time_goal = build_goal_event(HOME_TEAM_ID, "stoppage", 47);
+        stoppage_time_goal.period_id = 1;
+        stoppage_time_goal.time_str = Some("45+2'".into());
+        let second_half_goal = build_goal_event(HOME_TEAM_ID, "second half", 46);
+        let mut no_time_str_goal = build_goal_event(HOME_TEAM_ID, "no time", 30);
+        no_time_str_goal.time_str = None;
+
+        let events = vec![
+            Event::Goal(second_half_goal),
+            Event::Goal(stoppage_time_goal),
+            Event::Goal(no_time_str_goal),
+        ];
+
+        let ordered = timeline(&events)
+            .into_iter()
+            .filter_map(|event| event.player_name())
+            .collect::<Vec<_>>();
+        assert_eq!(ordered, vec!["no time", "stoppage", "second half"]);
+    }
+}
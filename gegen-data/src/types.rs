@@ -1,14 +1,69 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt, sync::Arc};
 
-use chrono::{DateTime, Utc, serde::ts_seconds};
+use chrono::{DateTime, Local, Utc, serde::ts_seconds};
 use serde::{Deserialize, Serialize};
 
+/// Defines a cheaply clonable string identifier so ids for different entities can't be mixed up
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(Arc<str>);
+
+        impl $name {
+            pub fn new(id: impl Into<Arc<str>>) -> Self {
+                Self(id.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self::new(id)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self::new(id)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// Identifies a single match
+    MatchId
+);
+id_type!(
+    /// Identifies a team, stable across matches and competitions
+    TeamId
+);
+id_type!(
+    /// Identifies a competition, e.g. a domestic league or cup
+    CompetitionId
+);
+id_type!(
+    /// Identifies a player
+    PlayerId
+);
+
 #[derive(Serialize)]
 pub(crate) struct LiveScoreQueryParams {
     pub(crate) offset: u8,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LiveScoresResponse {
     pub matches: Vec<Match>,
@@ -17,13 +72,16 @@ pub struct LiveScoresResponse {
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Match {
-    pub id: String,
+    pub id: MatchId,
     /// 1: first half
     /// 2: second half
     /// 3: first half of extra time
     /// 4: second half of extra time
     /// 5: penalties
     /// 10: half time
+    /// 11: end of second half, waiting for extra time
+    /// 12: half time of extra time
+    /// 13: end of extra time, waiting for penalties
     /// 14: full time
     /// 16: yet to start
     pub period: usize,
@@ -44,9 +102,245 @@ impl Match {
     pub fn try_get_score(&self, score_key: &ScoreKey) -> Option<&Score> {
         self.score.as_ref().and_then(|scores| scores.get(score_key))
     }
+
+    pub fn current_period(&self) -> Period {
+        Period::from(self.period)
+    }
+
+    /// whether the match is in play, including breaks between periods
+    pub fn is_live(&self) -> bool {
+        self.current_period().is_in_play() && !self.status.is_disrupted()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_period() == Period::FullTime || matches!(self.status, Status::Played)
+    }
+
+    /// The score as it currently stands, including extra time but not penalties.
+    ///
+    /// Unconfirmed scores take precedence as they are the most up to date during a match.
+    pub fn current_score(&self) -> Option<Score> {
+        [
+            ScoreKey::TotalUnconfirmed,
+            ScoreKey::Total,
+            ScoreKey::Et,
+            ScoreKey::Ft,
+            ScoreKey::Ht,
+        ]
+        .iter()
+        .find_map(|key| self.try_get_score(key))
+        .copied()
+    }
+
+    pub fn score_summary(&self) -> ScoreSummary {
+        ScoreSummary {
+            current: self.current_score(),
+            half_time: self.try_get_score(&ScoreKey::Ht).copied(),
+            full_time: self.try_get_score(&ScoreKey::Ft).copied(),
+            extra_time: self.try_get_score(&ScoreKey::Et).copied(),
+            penalties: self
+                .try_get_score(&ScoreKey::Pen)
+                .copied()
+                .or_else(|| self.shootout().map(|shootout| shootout.score())),
+            aggregate: self.try_get_score(&ScoreKey::Aggregate).copied(),
+            unconfirmed: self.try_get_score(&ScoreKey::TotalUnconfirmed).is_some(),
+        }
+    }
+
+    /// The winner of the tie once the match is finished.
+    ///
+    /// Penalties take precedence, then the aggregate score for two legged ties and finally the
+    /// score after extra time. `None` is returned for draws and for matches that are not finished.
+    pub fn winner(&self) -> Option<Winner> {
+        if !self.is_finished() {
+            return None;
+        }
+
+        let summary = self.score_summary();
+
+        if let Some(penalties) = summary.penalties {
+            return penalties.leader().map(|side| Winner {
+                side,
+                decided_by: Decider::Penalties,
+            });
+        }
+
+        if let Some(aggregate) = summary.aggregate {
+            return aggregate.leader().map(|side| Winner {
+                side,
+                decided_by: Decider::Aggregate,
+            });
+        }
+
+        let decided_by = if summary.extra_time.is_some() {
+            Decider::ExtraTime
+        } else {
+            Decider::RegularTime
+        };
+
+        summary
+            .current?
+            .leader()
+            .map(|side| Winner { side, decided_by })
+    }
+
+    pub fn side_of(&self, team_id: &TeamId) -> Option<Side> {
+        if self.home.id.as_ref() == Some(team_id) {
+            Some(Side::Home)
+        } else if self.away.id.as_ref() == Some(team_id) {
+            Some(Side::Away)
+        } else {
+            None
+        }
+    }
+
+    /// The result of this match for the given team, based on the score after extra time.
+    ///
+    /// Matches settled by penalties count as a draw, aggregate scores are not considered.
+    pub fn result_for(&self, team_id: &TeamId) -> Option<MatchResult> {
+        if !self.is_finished() {
+            return None;
+        }
+        let side = self.side_of(team_id)?;
+        let score = self.current_score()?;
+
+        let result = match score.leader() {
+            None => MatchResult::Draw,
+            Some(leader) if leader == side => MatchResult::Win,
+            Some(_) => MatchResult::Loss,
+        };
+        Some(result)
+    }
+
+    pub fn kickoff_local(&self) -> DateTime<Local> {
+        self.date.with_timezone(&Local)
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// Typed view of [`Match::period`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    FirstHalf,
+    SecondHalf,
+    ExtraTimeFirstHalf,
+    ExtraTimeSecondHalf,
+    Penalties,
+    HalfTime,
+    ExtraTimeBreak,
+    ExtraTimeHalfTime,
+    PenaltiesBreak,
+    FullTime,
+    PreMatch,
+    Unknown(usize),
+}
+
+impl From<usize> for Period {
+    fn from(period: usize) -> Self {
+        match period {
+            1 => Period::FirstHalf,
+            2 => Period::SecondHalf,
+            3 => Period::ExtraTimeFirstHalf,
+            4 => Period::ExtraTimeSecondHalf,
+            5 => Period::Penalties,
+            10 => Period::HalfTime,
+            11 => Period::ExtraTimeBreak,
+            12 => Period::ExtraTimeHalfTime,
+            13 => Period::PenaltiesBreak,
+            14 => Period::FullTime,
+            16 => Period::PreMatch,
+            other => Period::Unknown(other),
+        }
+    }
+}
+
+impl Period {
+    /// true while the match is being played or is in a break between periods
+    pub fn is_in_play(self) -> bool {
+        self.is_playing() || self.is_break()
+    }
+
+    /// true while the ball is in play, including a penalty shootout
+    pub fn is_playing(self) -> bool {
+        matches!(
+            self,
+            Period::FirstHalf
+                | Period::SecondHalf
+                | Period::ExtraTimeFirstHalf
+                | Period::ExtraTimeSecondHalf
+                | Period::Penalties
+        )
+    }
+
+    pub fn is_break(self) -> bool {
+        matches!(
+            self,
+            Period::HalfTime
+                | Period::ExtraTimeBreak
+                | Period::ExtraTimeHalfTime
+                | Period::PenaltiesBreak
+        )
+    }
+
+    pub fn is_extra_time(self) -> bool {
+        matches!(
+            self,
+            Period::ExtraTimeFirstHalf | Period::ExtraTimeSecondHalf | Period::ExtraTimeHalfTime
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Home,
+    Away,
+}
+
+impl Side {
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Home => Side::Away,
+            Side::Away => Side::Home,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decider {
+    RegularTime,
+    ExtraTime,
+    Penalties,
+    Aggregate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Winner {
+    pub side: Side,
+    pub decided_by: Decider,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// All of the scores known for a match
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScoreSummary {
+    /// see [`Match::current_score`]
+    pub current: Option<Score>,
+    pub half_time: Option<Score>,
+    pub full_time: Option<Score>,
+    pub extra_time: Option<Score>,
+    /// the shootout score, taken from the penalty events while the shootout is in progress
+    pub penalties: Option<Score>,
+    pub aggregate: Option<Score>,
+    /// true if `current` has not yet been confirmed by the data provider
+    pub unconfirmed: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase", tag = "entity_type")]
 pub enum Event {
     Sub(SubEvent),
@@ -57,7 +351,7 @@ pub enum Event {
 }
 
 impl Event {
-    pub fn get_team_id(&self) -> &String {
+    pub fn get_team_id(&self) -> &TeamId {
         match self {
             Event::Sub(sub_event) => &sub_event.team_id,
             Event::Goal(goal_event) => &goal_event.team_id,
@@ -78,20 +372,20 @@ impl Event {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase", tag = "entity_type")]
 pub struct PenaltyEvent {
     pub period_id: u8,
     pub min: u16,
     pub time_str: Option<String>,
-    pub team_id: String,
-    pub player_id: String,
+    pub team_id: TeamId,
+    pub player_id: PlayerId,
     pub player_name: String,
     pub outcome: PenaltyOutcome,
     pub pen_num: usize,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum PenaltyOutcome {
     Saved,
@@ -99,14 +393,14 @@ pub enum PenaltyOutcome {
     Missed,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct VAREvent {
     pub period_id: u8,
     pub min: u16,
     pub time_str: Option<String>,
-    pub team_id: String,
-    pub player_id: String,
+    pub team_id: TeamId,
+    pub player_id: PlayerId,
     pub player_name: String,
     #[serde(rename = "type")]
     pub var_type: String,
@@ -114,7 +408,7 @@ pub struct VAREvent {
     pub decision: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct CardEvent {
     pub period_id: u8,
@@ -122,12 +416,12 @@ pub struct CardEvent {
     pub time_str: Option<String>,
     pub player_name: Option<String>,
     pub reason: Option<String>,
-    pub team_id: String,
+    pub team_id: TeamId,
     #[serde(rename = "type")]
     pub card_type: Card,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
 pub enum Card {
     #[serde(rename = "YC")]
     Yellow,
@@ -137,14 +431,14 @@ pub enum Card {
     Red,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct GoalEvent {
     pub period_id: u8,
     pub min: u16,
     pub time_str: Option<String>,
-    pub team_id: String,
-    pub player_id: String,
+    pub team_id: TeamId,
+    pub player_id: PlayerId,
     pub player_name: String,
     pub player_2_name: Option<String>,
     #[serde(rename = "type")]
@@ -152,7 +446,7 @@ pub struct GoalEvent {
     pub score: Option<[u8; 2]>,
 }
 
-#[derive(Debug, Deserialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
 pub enum GoalType {
     #[serde(rename = "G")]
     Goal,
@@ -162,16 +456,16 @@ pub enum GoalType {
     OwnGoal,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct SubEvent {
     pub period_id: u8,
     pub min: u16,
     pub time_str: Option<String>,
-    pub team_id: String,
-    pub player_id: String,
+    pub team_id: TeamId,
+    pub player_id: PlayerId,
     pub player_name: String,
-    pub player2_id: String,
+    pub player2_id: PlayerId,
     pub player2_name: String,
 }
 
@@ -187,17 +481,35 @@ pub enum ScoreKey {
     Et,
 }
 
-#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Score {
     pub home: u8,
     pub away: u8,
 }
 
+impl Score {
+    /// the side that is ahead, `None` if the scores are level
+    pub fn leader(&self) -> Option<Side> {
+        match self.home.cmp(&self.away) {
+            std::cmp::Ordering::Greater => Some(Side::Home),
+            std::cmp::Ordering::Less => Some(Side::Away),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    pub fn for_side(&self, side: Side) -> u8 {
+        match side {
+            Side::Home => self.home,
+            Side::Away => self.away,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Competition {
-    pub id: String,
+    pub id: CompetitionId,
     pub name: String,
     pub country: Country,
 }
@@ -213,11 +525,11 @@ pub struct Country {
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Team {
-    pub id: Option<String>,
+    pub id: Option<TeamId>,
     pub name: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum Status {
     Played,
@@ -225,4 +537,88 @@ pub enum Status {
     Playing,
     Postponed,
     Suspended,
+    Abandoned,
+    Cancelled,
+    /// a status this version of gegen-data doesn't know about
+    #[serde(other)]
+    Unknown,
+}
+
+impl Status {
+    /// true for matches that have not been, or will not be, played to completion as scheduled
+    pub fn is_disrupted(self) -> bool {
+        matches!(
+            self,
+            Status::Postponed | Status::Suspended | Status::Abandoned | Status::Cancelled
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{AWAY_TEAM_ID, HOME_TEAM_ID, build_match};
+
+    #[test]
+    fn test_current_score_prefers_unconfirmed() {
+        let m = build_match(
+            2,
+            Status::Playing,
+            &[(ScoreKey::Total, 1, 0), (ScoreKey::TotalUnconfirmed, 1, 1)],
+        );
+        assert_eq!(m.current_score(), Some(Score { home: 1, away: 1 }));
+        assert!(m.score_summary().unconfirmed);
+        assert!(m.is_live());
+    }
+
+    #[test]
+    fn test_winner_on_penalties() {
+        let m = build_match(
+            14,
+            Status::Played,
+            &[
+                (ScoreKey::Total, 1, 1),
+                (ScoreKey::Et, 1, 1),
+                (ScoreKey::Pen, 3, 4),
+            ],
+        );
+        assert_eq!(
+            m.winner(),
+            Some(Winner {
+                side: Side::Away,
+                decided_by: Decider::Penalties
+            })
+        );
+        assert_eq!(
+            m.result_for(&TeamId::new(HOME_TEAM_ID)),
+            Some(MatchResult::Draw)
+        );
+    }
+
+    #[test]
+    fn test_winner_on_aggregate() {
+        let m = build_match(
+            14,
+            Status::Played,
+            &[(ScoreKey::Total, 0, 1), (ScoreKey::Aggregate, 3, 1)],
+        );
+        assert_eq!(
+            m.winner(),
+            Some(Winner {
+                side: Side::Home,
+                decided_by: Decider::Aggregate
+            })
+        );
+        assert_eq!(
+            m.result_for(&TeamId::new(AWAY_TEAM_ID)),
+            Some(MatchResult::Win)
+        );
+    }
+
+    #[test]
+    fn test_no_winner_before_full_time() {
+        let m = build_match(1, Status::Playing, &[(ScoreKey::Total, 2, 0)]);
+        assert_eq!(m.winner(), None);
+        assert_eq!(m.result_for(&TeamId::new(HOME_TEAM_ID)), None);
+    }
 }
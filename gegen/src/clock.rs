@@ -0,0 +1,177 @@
+use std::{ops::Deref, str::FromStr};
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use gegen_data::{
+    schedule,
+    types::{LiveScoresResponse, Match},
+};
+use serde::Deserialize;
+
+/// The timezone times and dates are shown in, set with `timezones` in the `[time]` section of
+/// the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub(crate) enum DisplayTimezone {
+    /// the timezone of the machine gegen is running on
+    Local,
+    Named(Tz),
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("`{0}` is not a timezone, use `local`, `utc` or a name such as `America/Sao_Paulo`")]
+pub(crate) struct InvalidTimezone(String);
+
+impl FromStr for DisplayTimezone {
+    type Err = InvalidTimezone;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("local") {
+            return Ok(DisplayTimezone::Local);
+        }
+        chrono_tz::TZ_VARIANTS
+            .iter()
+            .find(|tz| tz.name().eq_ignore_ascii_case(s))
+            .map(|tz| DisplayTimezone::Named(*tz))
+            .ok_or_else(|| InvalidTimezone(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for DisplayTimezone {
+    type Error = InvalidTimezone;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// 24 hour times such as `19:45` or 12 hour times such as `7:45pm`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+pub(crate) enum ClockFormat {
+    #[default]
+    #[serde(rename = "24h")]
+    TwentyFourHour,
+    #[serde(rename = "12h")]
+    TwelveHour,
+}
+
+impl ClockFormat {
+    pub(crate) fn toggled(self) -> Self {
+        match self {
+            ClockFormat::TwentyFourHour => ClockFormat::TwelveHour,
+            ClockFormat::TwelveHour => ClockFormat::TwentyFourHour,
+        }
+    }
+}
+
+/// How times and dates are shown, every kick off time, date and last updated time goes through
+/// this so they agree with each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Clock {
+    pub(crate) timezone: DisplayTimezone,
+    pub(crate) format: ClockFormat,
+}
+
+impl Clock {
+    /// today's date in the display timezone
+    pub(crate) fn today(&self) -> NaiveDate {
+        self.date_of(Utc::now())
+    }
+
+    pub(crate) fn date_of(&self, time: DateTime<Utc>) -> NaiveDate {
+        self.naive(time).date()
+    }
+
+    /// the time of day, e.g. `19:45` or `7:45pm`
+    pub(crate) fn format_time(&self, time: DateTime<Utc>) -> String {
+        let format = match self.format {
+            ClockFormat::TwentyFourHour => "%H:%M",
+            ClockFormat::TwelveHour => "%-I:%M%P",
+        };
+        self.naive(time).format(format).to_string()
+    }
+
+    /// the time of day with seconds, for when data was last updated
+    pub(crate) fn format_time_with_seconds(&self, time: DateTime<Utc>) -> String {
+        let format = match self.format {
+            ClockFormat::TwentyFourHour => "%H:%M:%S",
+            ClockFormat::TwelveHour => "%-I:%M:%S%P",
+        };
+        self.naive(time).format(format).to_string()
+    }
+
+    /// the name of the timezone, `None` for the local timezone as that's what's expected
+    pub(crate) fn timezone_label(&self) -> Option<String> {
+        match self.timezone {
+            DisplayTimezone::Local => None,
+            DisplayTimezone::Named(tz) => Some(tz.name().to_string()),
+        }
+    }
+
+    /// matches kicking off on `date` in the display timezone, see
+    /// [`schedule::matches_on_date`]
+    pub(crate) fn matches_on_date(
+        &self,
+        api_days: impl IntoIterator<Item = impl Deref<Target = LiveScoresResponse>>,
+        date: NaiveDate,
+    ) -> Vec<Match> {
+        match self.timezone {
+            DisplayTimezone::Local => schedule::matches_on_date(api_days, date, &Local),
+            DisplayTimezone::Named(tz) => schedule::matches_on_date(api_days, date, &tz),
+        }
+    }
+
+    fn naive(&self, time: DateTime<Utc>) -> NaiveDateTime {
+        match self.timezone {
+            DisplayTimezone::Local => time.with_timezone(&Local).naive_local(),
+            DisplayTimezone::Named(tz) => time.with_timezone(&tz).naive_local(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_timezones() {
+        assert_eq!("Local".parse(), Ok(DisplayTimezone::Local));
+        assert_eq!("utc".parse(), Ok(DisplayTimezone::Named(Tz::UTC)));
+        assert_eq!(
+            "america/sao_paulo".parse(),
+            Ok(DisplayTimezone::Named(Tz::America__Sao_Paulo))
+        );
+        assert_eq!(
+            "Mars/Olympus".parse::<DisplayTimezone>(),
+            Err(InvalidTimezone("Mars/Olympus".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_format_in_timezone() {
+        // 23:30 UTC is 19:30 in new york during daylight saving time
+        let kickoff = Utc.with_ymd_and_hms(2025, 4, 26, 23, 30, 0).unwrap();
+        let new_york = Clock {
+            timezone: DisplayTimezone::Named(Tz::America__New_York),
+            format: ClockFormat::TwelveHour,
+        };
+        assert_eq!(new_york.format_time(kickoff), "7:30pm");
+        assert_eq!(
+            new_york.date_of(kickoff),
+            NaiveDate::from_ymd_opt(2025, 4, 26).unwrap()
+        );
+
+        let berlin = Clock {
+            timezone: DisplayTimezone::Named(Tz::Europe__Berlin),
+            format: ClockFormat::TwentyFourHour,
+        };
+        assert_eq!(berlin.format_time(kickoff), "01:30");
+        assert_eq!(berlin.format_time_with_seconds(kickoff), "01:30:00");
+        assert_eq!(
+            berlin.date_of(kickoff),
+            NaiveDate::from_ymd_opt(2025, 4, 27).unwrap()
+        );
+    }
+}
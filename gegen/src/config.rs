@@ -0,0 +1,659 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use dirs::config_dir;
+use gegen_data::{
+    standings::{DEFAULT_TIEBREAKERS, Tiebreaker},
+    types::Competition,
+};
+use serde::Deserialize;
+
+use crate::{
+    clock::{Clock, ClockFormat, DisplayTimezone},
+    ui::{
+        keymap::{Action, ConfiguredKeys, Keymap, KeymapError},
+        theme::{Theme, ThemeColors, ThemePreset, no_color_requested},
+    },
+};
+
+const CONFIG_FILE_NAME: &str = "gegen/config.toml";
+/// how often the config file is checked for changes
+const RELOAD_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+const MIN_TICK_RATE_MS: u64 = 16;
+const MAX_TICK_RATE_MS: u64 = 5000;
+/// polling the API more frequently than this risks being rate limited
+const MIN_FETCH_DELAY_SECS: u64 = 2;
+/// each day is a request to the API, so the competition page is limited to a couple of months
+const MAX_COMPETITION_PAGE_DAYS: u64 = 30;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Config {
+    pub(crate) general: GeneralConfig,
+    pub(crate) data: DataConfig,
+    pub(crate) logging: LoggingConfig,
+    pub(crate) competitions: CompetitionsConfig,
+    pub(crate) theme: ThemeConfig,
+    /// themes defined in the config file, picked with `name` in `[theme]`
+    pub(crate) themes: BTreeMap<String, NamedTheme>,
+    pub(crate) time: TimeConfig,
+    pub(crate) standings: StandingsConfig,
+    /// keys for each action, replacing the default keys of that action
+    pub(crate) keys: HashMap<Action, ConfiguredKeys>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct GeneralConfig {
+    /// how often the UI is redrawn
+    pub(crate) tick_rate_ms: u64,
+    /// whether mouse capture is enabled on start up, it can be toggled while running
+    pub(crate) mouse: bool,
+    /// whether competitions are listed in a sidebar on start up rather than as compact tabs, it
+    /// can be toggled while running
+    pub(crate) sidebar: bool,
+}
+
+impl Default for GeneralConfig {
+    fn default() -> Self {
+        Self {
+            tick_rate_ms: 150,
+            mouse: true,
+            sidebar: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct DataConfig {
+    /// how often live scores are fetched
+    pub(crate) fetch_delay_secs: u64,
+}
+
+impl Default for DataConfig {
+    fn default() -> Self {
+        Self {
+            fetch_delay_secs: 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct LoggingConfig {
+    /// number of hourly log files to keep, only applied on start up
+    pub(crate) max_log_files: usize,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self { max_log_files: 10 }
+    }
+}
+
+/// Competitions are given as `"Country - Competition"`, e.g. `"England - Premier League"`, or just
+/// the competition name to match it in any country. Matching ignores case
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct CompetitionsConfig {
+    /// competitions listed first, in this order, the rest keep the order they are returned in
+    pub(crate) priority: Vec<String>,
+    pub(crate) hide: Vec<String>,
+    pub(crate) hide_countries: Vec<String>,
+    /// days either side of today shown on the competition page
+    pub(crate) page_days: u64,
+}
+
+/// names vary between competitions and data providers so common variants are all listed
+const DEFAULT_PRIORITY: [&str; 16] = [
+    "Champions League",
+    "UEFA Champions League",
+    "Europa League",
+    "UEFA Europa League",
+    "Conference League",
+    "UEFA Conference League",
+    "World Cup",
+    "FIFA World Cup",
+    "European Championship",
+    "UEFA European Championship",
+    "England - Premier League",
+    "Spain - LaLiga",
+    "Spain - La Liga",
+    "Germany - Bundesliga",
+    "Italy - Serie A",
+    "France - Ligue 1",
+];
+
+impl Default for CompetitionsConfig {
+    fn default() -> Self {
+        Self {
+            priority: DEFAULT_PRIORITY.map(String::from).to_vec(),
+            hide: Vec::new(),
+            hide_countries: Vec::new(),
+            page_days: 7,
+        }
+    }
+}
+
+impl CompetitionsConfig {
+    pub(crate) fn is_hidden(&self, competition: &Competition) -> bool {
+        self.hide_countries
+            .iter()
+            .any(|country| country.eq_ignore_ascii_case(&competition.country.full_name))
+            || self
+                .hide
+                .iter()
+                .any(|entry| matches_competition(entry, competition))
+    }
+
+    /// position in the priority list, competitions that aren't in the list have no priority
+    pub(crate) fn priority(&self, competition: &Competition) -> Option<usize> {
+        self.priority
+            .iter()
+            .position(|entry| matches_competition(entry, competition))
+    }
+}
+
+fn matches_competition(entry: &str, competition: &Competition) -> bool {
+    match entry.split_once(" - ") {
+        Some((country, name)) => {
+            country.eq_ignore_ascii_case(&competition.country.full_name)
+                && name.eq_ignore_ascii_case(&competition.name)
+        }
+        None => entry.eq_ignore_ascii_case(&competition.name),
+    }
+}
+
+/// How teams level on points are separated on the standings page
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct StandingsConfig {
+    /// used for every competition that isn't in `competitions`
+    pub(crate) tiebreakers: Vec<Tiebreaker>,
+    /// tiebreakers for particular competitions, given the same way as in `[competitions]`. An
+    /// entry with the country takes precedence over one with only the name
+    pub(crate) competitions: BTreeMap<String, Vec<Tiebreaker>>,
+}
+
+impl Default for StandingsConfig {
+    fn default() -> Self {
+        Self {
+            tiebreakers: DEFAULT_TIEBREAKERS.to_vec(),
+            competitions: BTreeMap::new(),
+        }
+    }
+}
+
+impl StandingsConfig {
+    pub(crate) fn tiebreakers(&self, competition: &Competition) -> &[Tiebreaker] {
+        self.competitions
+            .iter()
+            .filter(|(entry, _)| matches_competition(entry, competition))
+            .max_by_key(|(entry, _)| entry.contains(" - "))
+            .map_or(&self.tiebreakers, |(_, tiebreakers)| tiebreakers)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct ThemeConfig {
+    /// when not set the dark theme is used, or the monochrome theme if `NO_COLOR` is set
+    pub(crate) preset: Option<ThemePreset>,
+    /// one of the themes in `[themes]`, used instead of a preset
+    pub(crate) name: Option<String>,
+    /// applied on top of the preset or named theme
+    pub(crate) colors: ThemeColors,
+}
+
+/// A theme defined under `[themes.<name>]`, a preset with some of its colours changed
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct NamedTheme {
+    /// the preset the theme starts from, dark when not set
+    pub(crate) preset: Option<ThemePreset>,
+    pub(crate) colors: ThemeColors,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct TimeConfig {
+    /// timezones that can be switched between while running, the first is used on start up
+    pub(crate) timezones: Vec<DisplayTimezone>,
+    pub(crate) clock: ClockFormat,
+}
+
+impl Default for TimeConfig {
+    fn default() -> Self {
+        Self {
+            timezones: vec![DisplayTimezone::Local],
+            clock: ClockFormat::default(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ConfigError {
+    #[error("Failed to read config file {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse config file {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("Invalid value for `{field}` in config file: {reason}")]
+    Invalid { field: &'static str, reason: String },
+}
+
+impl Config {
+    pub(crate) fn tick_rate(&self) -> Duration {
+        Duration::from_millis(self.general.tick_rate_ms)
+    }
+
+    pub(crate) fn fetch_delay(&self) -> Duration {
+        Duration::from_secs(self.data.fetch_delay_secs)
+    }
+
+    pub(crate) fn theme(&self) -> Theme {
+        match self
+            .theme
+            .name
+            .as_ref()
+            .and_then(|name| self.themes.get(name))
+        {
+            // picking a theme by name is as explicit as picking a preset, so `NO_COLOR` is ignored
+            Some(named_theme) => Theme::new(
+                Some(named_theme.preset.unwrap_or_default()),
+                &named_theme.colors,
+                false,
+            )
+            .with_overrides(&self.theme.colors),
+            None => Theme::new(self.theme.preset, &self.theme.colors, no_color_requested()),
+        }
+    }
+
+    pub(crate) fn clock(&self) -> Clock {
+        Clock {
+            timezone: self
+                .time
+                .timezones
+                .first()
+                .copied()
+                .unwrap_or(DisplayTimezone::Local),
+            format: self.time.clock,
+        }
+    }
+
+    pub(crate) fn keymap(&self) -> Result<Keymap, KeymapError> {
+        Keymap::new(&self.keys)
+    }
+
+    /// location of the config file, e.g. `$XDG_CONFIG_HOME/gegen/config.toml` on linux
+    pub(crate) fn path() -> Option<PathBuf> {
+        config_dir().map(|config_dir| config_dir.join(CONFIG_FILE_NAME))
+    }
+
+    /// Loads the config file, a missing file isn't an error and gives the default config
+    pub(crate) fn load(path: &Path) -> Result<Self, ConfigError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(source) => {
+                return Err(ConfigError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                });
+            }
+        };
+
+        let config = Self::parse(&contents).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !(MIN_TICK_RATE_MS..=MAX_TICK_RATE_MS).contains(&self.general.tick_rate_ms) {
+            return Err(ConfigError::Invalid {
+                field: "general.tick_rate_ms",
+                reason: format!(
+                    "must be between {MIN_TICK_RATE_MS} and {MAX_TICK_RATE_MS}, got {}",
+                    self.general.tick_rate_ms
+                ),
+            });
+        }
+
+        if self.data.fetch_delay_secs < MIN_FETCH_DELAY_SECS {
+            return Err(ConfigError::Invalid {
+                field: "data.fetch_delay_secs",
+                reason: format!(
+                    "must be at least {MIN_FETCH_DELAY_SECS}, got {}",
+                    self.data.fetch_delay_secs
+                ),
+            });
+        }
+
+        if self.logging.max_log_files == 0 {
+            return Err(ConfigError::Invalid {
+                field: "logging.max_log_files",
+                reason: "must be at least 1".to_string(),
+            });
+        }
+
+        if !(1..=MAX_COMPETITION_PAGE_DAYS).contains(&self.competitions.page_days) {
+            return Err(ConfigError::Invalid {
+                field: "competitions.page_days",
+                reason: format!(
+                    "must be between 1 and {MAX_COMPETITION_PAGE_DAYS}, got {}",
+                    self.competitions.page_days
+                ),
+            });
+        }
+
+        if self.time.timezones.is_empty() {
+            return Err(ConfigError::Invalid {
+                field: "time.timezones",
+                reason: "must have at least one timezone".to_string(),
+            });
+        }
+
+        if let Some(name) = &self.theme.name {
+            if self.theme.preset.is_some() {
+                return Err(ConfigError::Invalid {
+                    field: "theme.name",
+                    reason: "can't be set as well as theme.preset".to_string(),
+                });
+            }
+            if !self.themes.contains_key(name) {
+                return Err(ConfigError::Invalid {
+                    field: "theme.name",
+                    reason: format!("there is no theme called {name:?} in [themes]"),
+                });
+            }
+        }
+
+        // names are matched ignoring case, so two entries that only differ by case would both
+        // apply to the same competition
+        let entries = self.standings.competitions.keys().collect::<Vec<_>>();
+        for (i, entry) in entries.iter().enumerate() {
+            if let Some(duplicate) = entries[i + 1..]
+                .iter()
+                .find(|other| other.eq_ignore_ascii_case(entry))
+            {
+                return Err(ConfigError::Invalid {
+                    field: "standings.competitions",
+                    reason: format!("{entry:?} and {duplicate:?} are the same competition"),
+                });
+            }
+        }
+
+        if let Err(err) = self.keymap() {
+            return Err(ConfigError::Invalid {
+                field: "keys",
+                reason: err.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Watches the config file for changes by checking its modified time
+pub(crate) struct ConfigWatcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+    last_checked: Instant,
+}
+
+impl ConfigWatcher {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        let last_modified = modified_time(&path);
+        Self {
+            path,
+            last_modified,
+            last_checked: Instant::now(),
+        }
+    }
+
+    /// returns the reloaded config if the file has changed since it was last loaded
+    pub(crate) fn poll(&mut self) -> Option<Result<Config, ConfigError>> {
+        if self.last_checked.elapsed() < RELOAD_CHECK_INTERVAL {
+            return None;
+        }
+        self.last_checked = Instant::now();
+
+        let modified = modified_time(&self.path);
+        if modified == self.last_modified {
+            return None;
+        }
+        self.last_modified = modified;
+
+        tracing::info!("config file {} changed, reloading", self.path.display());
+        Some(Config::load(&self.path))
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use gegen_data::types::Country;
+
+    use super::*;
+
+    #[test]
+    fn test_partial_config_uses_defaults() {
+        let config = Config::parse("[data]\nfetch_delay_secs = 10").unwrap();
+        assert_eq!(config.data.fetch_delay_secs, 10);
+        assert_eq!(config.general, GeneralConfig::default());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        assert!(Config::parse("[data]\nfetch_delay = 10").is_err());
+    }
+
+    #[test]
+    fn test_theme_config() {
+        let config = Config::parse(
+            "[theme]\npreset = \"high-contrast\"\n\n[theme.colors]\nlive = \"#ff5f00\"",
+        )
+        .unwrap();
+        assert_eq!(config.theme.preset, Some(ThemePreset::HighContrast));
+        assert_eq!(
+            config.theme.colors.live,
+            Some(ratatui::style::Color::Rgb(255, 95, 0))
+        );
+    }
+
+    #[test]
+    fn test_named_theme() {
+        use ratatui::style::Color;
+
+        let config = Config::parse(
+            "[theme]\nname = \"pitch\"\n\n[theme.colors]\nwin = \"blue\"\n\n[themes.pitch]\npreset = \"light\"\n\n[themes.pitch.colors]\nborder = \"green\"\nwin = \"yellow\"",
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        let theme = config.theme();
+        assert_eq!(theme.border, Color::Green);
+        // the overrides in [theme.colors] win over the named theme's colours
+        assert_eq!(theme.win, Color::Blue);
+        assert_eq!(theme.title, Theme::preset(ThemePreset::Light).title);
+
+        let config = Config::parse("[theme]\nname = \"missing\"").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                field: "theme.name",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_keys_config() {
+        let config =
+            Config::parse("[keys]\nnext_day = \"l\"\nprevious_day = [\"h\", \"left\"]").unwrap();
+        assert!(config.validate().is_ok());
+
+        let config = Config::parse("[keys]\nnext_day = \"q\"").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid { field: "keys", .. })
+        ));
+
+        assert!(Config::parse("[keys]\nnot_an_action = \"x\"").is_err());
+    }
+
+    #[test]
+    fn test_competitions_config() {
+        let competition = |country: &str, name: &str| Competition {
+            id: name.into(),
+            name: name.to_string(),
+            country: Country {
+                id: country.to_string(),
+                full_name: country.to_string(),
+            },
+        };
+
+        let config = Config::parse(
+            "[competitions]\npriority = [\"Champions League\", \"england - premier league\"]\nhide = [\"England - Premier League 2\"]\nhide_countries = [\"Wales\"]",
+        )
+        .unwrap();
+        let competitions = config.competitions;
+
+        assert_eq!(
+            competitions.priority(&competition("Europe", "Champions League")),
+            Some(0)
+        );
+        assert_eq!(
+            competitions.priority(&competition("England", "Premier League")),
+            Some(1)
+        );
+        assert_eq!(
+            competitions.priority(&competition("Scotland", "Premier League")),
+            None
+        );
+
+        assert!(competitions.is_hidden(&competition("England", "Premier League 2")));
+        assert!(competitions.is_hidden(&competition("Wales", "Cymru Premier")));
+        assert!(!competitions.is_hidden(&competition("England", "Premier League")));
+    }
+
+    #[test]
+    fn test_time_config() {
+        let config =
+            Config::parse("[time]\ntimezones = [\"UTC\", \"local\"]\nclock = \"12h\"").unwrap();
+        assert_eq!(
+            config.clock(),
+            Clock {
+                timezone: DisplayTimezone::Named(chrono_tz::Tz::UTC),
+                format: ClockFormat::TwelveHour,
+            }
+        );
+        assert!(config.validate().is_ok());
+
+        assert!(Config::parse("[time]\ntimezones = [\"Nowhere/Special\"]").is_err());
+        assert!(Config::parse("[time]\nclock = \"13h\"").is_err());
+
+        let config = Config::parse("[time]\ntimezones = []").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                field: "time.timezones",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_standings_config() {
+        let competition = |country: &str, name: &str| Competition {
+            id: name.into(),
+            name: name.to_string(),
+            country: Country {
+                id: country.to_string(),
+                full_name: country.to_string(),
+            },
+        };
+
+        let config = Config::parse(
+            "[standings]\ntiebreakers = [\"goal_difference\", \"wins\"]\n\n[standings.competitions]\n\"LaLiga\" = [\"wins\"]\n\"Spain - LaLiga\" = [\"head_to_head_points\", \"goal_difference\"]",
+        )
+        .unwrap();
+        let standings = config.standings;
+
+        assert_eq!(
+            standings.tiebreakers(&competition("Spain", "LaLiga")),
+            [Tiebreaker::HeadToHeadPoints, Tiebreaker::GoalDifference]
+        );
+        assert_eq!(
+            standings.tiebreakers(&competition("Mexico", "LaLiga")),
+            [Tiebreaker::Wins]
+        );
+        assert_eq!(
+            standings.tiebreakers(&competition("England", "Premier League")),
+            [Tiebreaker::GoalDifference, Tiebreaker::Wins]
+        );
+        assert_eq!(
+            Config::default()
+                .standings
+                .tiebreakers(&competition("England", "Premier League")),
+            DEFAULT_TIEBREAKERS
+        );
+
+        assert!(Config::parse("[standings]\ntiebreakers = [\"coin_toss\"]").is_err());
+    }
+
+    #[test]
+    fn test_validation() {
+        let config = Config::parse("[data]\nfetch_delay_secs = 1").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                field: "data.fetch_delay_secs",
+                ..
+            })
+        ));
+
+        let config = Config::parse("[competitions]\npage_days = 0").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                field: "competitions.page_days",
+                ..
+            })
+        ));
+
+        let config = Config::parse(
+            "[standings.competitions]\n\"LaLiga\" = [\"wins\"]\n\"laliga\" = [\"goal_difference\"]",
+        )
+        .unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                field: "standings.competitions",
+                ..
+            })
+        ));
+    }
+}
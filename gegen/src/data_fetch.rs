@@ -1,62 +1,140 @@
-use std::time::{Duration, SystemTime};
+use std::{
+    collections::VecDeque,
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Utc};
 use crossbeam::channel::Receiver;
-use gegen_data::{get_live_scores, get_matches};
+use gegen_data::{
+    feed::{FeedFilter, LiveFeed},
+    get_matches, schedule,
+    types::LiveScoresResponse,
+};
 
-use crate::state::LiveData;
+use crate::state::{DataVersion, LiveData};
 
-const FETCH_DELAY: Duration = Duration::from_secs(6);
 const DATA_FETCH_THREAD_NAME: &str = "data fetch thread";
 const SLEEP: Duration = Duration::from_millis(100);
+/// upper bound for the back off applied after failing to refresh the days either side of today
+const MAX_REFRESH_BACKOFF: Duration = Duration::from_secs(60);
 
-fn fetch_data(data: LiveData, current_date: NaiveDate, recv: Receiver<NaiveDate>) {
+/// Requests sent from the UI to the data fetch thread
+pub(crate) enum DataRequest {
+    /// fetch the fixtures for every date from the first to the last, inclusive, other than today
+    DateRange(NaiveDate, NaiveDate),
+    /// fetch the fixtures for the dates in the order given, ahead of any dates already waiting
+    Dates(Vec<NaiveDate>),
+    /// change how often live scores are fetched
+    SetFetchDelay(Duration),
+    /// today's date in the display timezone has changed, the live scores are stored under it
+    SetLiveDate(NaiveDate),
+}
+
+fn fetch_data(
+    data: LiveData,
+    data_version: DataVersion,
+    mut live_date: NaiveDate,
+    recv: Receiver<DataRequest>,
+    fetch_delay: Duration,
+) {
     let client = reqwest::blocking::Client::new();
     let mut failure_count = 0;
-    let mut last_fetched_live_date = SystemTime::now();
 
-    // prefetch
-    fetch_and_insert_data(
-        &client,
-        &data,
-        current_date,
-        &mut failure_count,
-        DataToFetch::Live,
-    );
+    let mut live_feed = LiveFeed::new(client.clone()).with_interval(fetch_delay);
+    live_feed.subscribe_with(FeedFilter::All, |update| {
+        for change in &update.changes {
+            tracing::info!(match_id = %update.match_data.id, "match changed: {change:?}");
+        }
+    });
+
+    // dates waiting to be fetched, the UI asks for a date on every tick until it's loaded so
+    // requests are collected here rather than each one being fetched
+    let mut pending_dates = VecDeque::new();
+    let mut fetch_delay = fetch_delay;
+    let mut last_refresh = Instant::now();
+    // refreshing the days either side of today is retried with a back off rather than counting
+    // towards the failures that stop the thread, as the live scores are still being fetched
+    let mut refresh_failures = 0;
 
     loop {
-        if let Ok(other_date) = recv.try_recv() {
+        for request in recv.try_iter() {
+            match request {
+                DataRequest::DateRange(first, last) => {
+                    for date in first.iter_days().take_while(|date| *date <= last) {
+                        if !pending_dates.contains(&date) {
+                            pending_dates.push_back(date);
+                        }
+                    }
+                }
+                DataRequest::Dates(dates) => {
+                    pending_dates.retain(|date| !dates.contains(date));
+                    for date in dates.into_iter().rev() {
+                        pending_dates.push_front(date);
+                    }
+                }
+                DataRequest::SetFetchDelay(new_fetch_delay) => {
+                    tracing::info!("fetching live data every {new_fetch_delay:?}");
+                    fetch_delay = new_fetch_delay;
+                    live_feed.set_interval(fetch_delay);
+                }
+                DataRequest::SetLiveDate(new_live_date) => {
+                    tracing::info!("storing live data under {new_live_date}");
+                    live_date = new_live_date;
+                }
+            }
+        }
+
+        // today's matches come from the live feed
+        pending_dates.retain(|date| *date != live_date && !data.contains_key(date));
+
+        // one date at a time so a range doesn't hold up the live scores
+        if let Some(other_date) = pending_dates.pop_front() {
             tracing::info!("fetching data for {other_date}");
             fetch_and_insert_data(
                 &client,
                 &data,
+                &data_version,
                 other_date,
                 &mut failure_count,
-                DataToFetch::Fixtures,
             );
         }
 
-        match last_fetched_live_date.elapsed() {
-            Ok(elapsed_since_last_fetch) => {
-                if elapsed_since_last_fetch > FETCH_DELAY {
+        // Matches kicking off late or early can be listed under the days either side of today
+        // rather than with the live scores, they're fetched again as often as the live scores
+        // until they've finished
+        if last_refresh.elapsed() >= refresh_delay(fetch_delay, refresh_failures) {
+            last_refresh = Instant::now();
+            for date in schedule::api_dates_for(live_date) {
+                if date != live_date
+                    && data
+                        .get(&date)
+                        .is_some_and(|api_day| has_unfinished_matches(&api_day))
+                {
+                    tracing::info!("refreshing data for {date}");
                     fetch_and_insert_data(
                         &client,
                         &data,
-                        current_date,
-                        &mut failure_count,
-                        DataToFetch::Live,
+                        &data_version,
+                        date,
+                        &mut refresh_failures,
                     );
-                    last_fetched_live_date = SystemTime::now();
                 }
             }
+        }
+
+        match live_feed.poll_if_due() {
+            Ok(Some(live_scores)) => {
+                data.insert(live_date, live_scores.clone());
+                data_version.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(None) => (),
             Err(err) => {
-                tracing::error!(
-                    "failed to calculate time elapsed since last fetch of live data: {err}"
-                );
+                tracing::error!("got error when fetching live data: {err}");
             }
         }
 
-        if failure_count >= 3 {
+        if failure_count >= 3 || live_feed.has_failed() {
             tracing::error!("encountered three consecutive failures to fetch data, aborting");
             break;
         }
@@ -68,20 +146,35 @@ fn fetch_data(data: LiveData, current_date: NaiveDate, recv: Receiver<NaiveDate>
     }
 }
 
+/// the fetch delay doubled for each consecutive failure to refresh
+fn refresh_delay(fetch_delay: Duration, refresh_failures: u32) -> Duration {
+    if refresh_failures == 0 {
+        return fetch_delay;
+    }
+    fetch_delay
+        .saturating_mul(2_u32.saturating_pow(refresh_failures))
+        .min(MAX_REFRESH_BACKOFF.max(fetch_delay))
+}
+
+/// whether any of the matches have kicked off but not finished yet
+fn has_unfinished_matches(api_day: &LiveScoresResponse) -> bool {
+    let now = Utc::now();
+    api_day.matches.iter().any(|match_data| {
+        match_data.date <= now && !match_data.is_finished() && !match_data.status.is_disrupted()
+    })
+}
+
 fn fetch_and_insert_data(
     client: &reqwest::blocking::Client,
     data: &LiveData,
+    data_version: &DataVersion,
     date: NaiveDate,
     failure_count: &mut u32,
-    data_to_fetch: DataToFetch,
 ) {
-    let response = match data_to_fetch {
-        DataToFetch::Live => get_live_scores(client),
-        DataToFetch::Fixtures => get_matches(client, date),
-    };
-    match response {
-        Ok(live_scores) => {
-            data.insert(date, live_scores);
+    match get_matches(client, date) {
+        Ok(matches) => {
+            data.insert(date, matches);
+            data_version.fetch_add(1, Ordering::Relaxed);
 
             *failure_count = 0;
         }
@@ -93,22 +186,20 @@ fn fetch_and_insert_data(
     }
 }
 
-enum DataToFetch {
-    Live,
-    Fixtures,
-}
-
 // run the data collection thread in the background
 pub(crate) fn run_data_fetch(
     data: &LiveData,
-    current_date: NaiveDate,
-    recv: Receiver<NaiveDate>,
+    data_version: &DataVersion,
+    live_date: NaiveDate,
+    recv: Receiver<DataRequest>,
+    fetch_delay: Duration,
 ) -> std::thread::JoinHandle<()> {
     std::thread::Builder::new()
         .name(DATA_FETCH_THREAD_NAME.into())
         .spawn({
             let data = data.clone();
-            move || fetch_data(data, current_date, recv)
+            let data_version = data_version.clone();
+            move || fetch_data(data, data_version, live_date, recv, fetch_delay)
         })
         .expect("Failed to run thread: {DATA_FETCH_THREAD_NAME}")
 }
@@ -0,0 +1,164 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use dirs::data_local_dir;
+use gegen_data::types::{CompetitionId, Match, Team, TeamId};
+use serde::{Deserialize, Serialize};
+
+const FAVOURITES_FILE_NAME: &str = "gegen/favourites.toml";
+
+/// Teams and competitions marked as favourites, keyed by id with the name kept alongside so the
+/// file is readable
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Favourites {
+    teams: BTreeMap<TeamId, String>,
+    competitions: BTreeMap<CompetitionId, String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum FavouritesError {
+    #[error("Failed to read favourites file {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse favourites file {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("Failed to write favourites file {path}: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to serialise favourites: {0}")]
+    Serialise(#[from] toml::ser::Error),
+}
+
+impl Favourites {
+    /// location of the favourites file, e.g. `$XDG_DATA_HOME/gegen/favourites.toml` on linux
+    pub(crate) fn path() -> Option<PathBuf> {
+        data_local_dir().map(|data_local_dir| data_local_dir.join(FAVOURITES_FILE_NAME))
+    }
+
+    /// Loads the favourites, a missing file isn't an error and gives no favourites
+    pub(crate) fn load(path: &Path) -> Result<Self, FavouritesError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(FavouritesError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                });
+            }
+        };
+
+        toml::from_str(&contents).map_err(|source| FavouritesError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Writes to a temporary file first so a crash part way through can't lose the favourites
+    pub(crate) fn save(&self, path: &Path) -> Result<(), FavouritesError> {
+        let contents = toml::to_string(self)?;
+        let write_error = |source| FavouritesError::Write {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(write_error)?;
+        }
+        let tmp_path = path.with_extension("toml.tmp");
+        std::fs::write(&tmp_path, contents).map_err(write_error)?;
+        std::fs::rename(&tmp_path, path).map_err(write_error)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.teams.is_empty() && self.competitions.is_empty()
+    }
+
+    pub(crate) fn is_favourite_team(&self, team: &Team) -> bool {
+        team.id
+            .as_ref()
+            .is_some_and(|team_id| self.teams.contains_key(team_id))
+    }
+
+    /// whether the match is between favourite teams or in a favourite competition
+    pub(crate) fn contains_match(&self, match_data: &Match) -> bool {
+        self.competitions.contains_key(&match_data.comp.id)
+            || self.is_favourite_team(&match_data.home)
+            || self.is_favourite_team(&match_data.away)
+    }
+
+    /// adds or removes the team, returning whether it's now a favourite. Teams without an id,
+    /// e.g. when the fixture is still to be decided, can't be favourites
+    pub(crate) fn toggle_team(&mut self, team: &Team) -> bool {
+        let Some(team_id) = &team.id else {
+            return false;
+        };
+
+        if self.teams.remove(team_id).is_some() {
+            false
+        } else {
+            self.teams
+                .insert(team_id.clone(), team.name.clone().unwrap_or_default());
+            true
+        }
+    }
+
+    /// adds or removes the competition of the match, returning whether it's now a favourite
+    pub(crate) fn toggle_competition(&mut self, match_data: &Match) -> bool {
+        let competition = &match_data.comp;
+        if self.competitions.remove(&competition.id).is_some() {
+            false
+        } else {
+            self.competitions.insert(
+                competition.id.clone(),
+                format!("{} - {}", competition.country.full_name, competition.name),
+            );
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team(id: &str, name: &str) -> Team {
+        Team {
+            id: Some(TeamId::new(id)),
+            name: Some(name.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_toggle_team() {
+        let mut favourites = Favourites::default();
+        let arsenal = team("t1", "Arsenal");
+
+        assert!(favourites.toggle_team(&arsenal));
+        assert!(favourites.is_favourite_team(&arsenal));
+        assert!(!favourites.is_favourite_team(&team("t2", "Spurs")));
+
+        assert!(!favourites.toggle_team(&arsenal));
+        assert!(favourites.is_empty());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut favourites = Favourites::default();
+        favourites.toggle_team(&team("t1", "Arsenal"));
+
+        let contents = toml::to_string(&favourites).unwrap();
+        assert_eq!(contents, "[teams]\nt1 = \"Arsenal\"\n\n[competitions]\n");
+        assert_eq!(toml::from_str::<Favourites>(&contents).unwrap(), favourites);
+    }
+}
@@ -1,19 +1,32 @@
 use std::path::PathBuf;
 
+use chrono::Local;
 use dirs::data_local_dir;
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 
-const MAX_LOG_FILE: usize = 10;
-
-pub(crate) fn create_file_appender() -> RollingFileAppender {
-    let data_local_dir = data_local_dir().expect("Failed to find data local directory");
+fn try_logs_dir() -> Option<PathBuf> {
     let gegen_dir = PathBuf::from("gegen/logs");
-    let logs_dir = data_local_dir.join(gegen_dir);
+    data_local_dir().map(|data_local_dir| data_local_dir.join(gegen_dir))
+}
 
+pub(crate) fn create_file_appender(max_log_files: usize) -> RollingFileAppender {
     tracing_appender::rolling::RollingFileAppender::builder()
-        .max_log_files(MAX_LOG_FILE)
+        .max_log_files(max_log_files)
         .rotation(Rotation::HOURLY)
         .filename_prefix("gegen.log")
-        .build(logs_dir)
+        .build(try_logs_dir().expect("Failed to find data local directory"))
         .expect("failed to build file appender")
 }
+
+/// writes a crash report to the logs directory, returning the path of the report
+pub(crate) fn write_crash_report(report: &str) -> std::io::Result<PathBuf> {
+    let Some(logs_dir) = try_logs_dir() else {
+        return Err(std::io::Error::other("failed to find data local directory"));
+    };
+    std::fs::create_dir_all(&logs_dir)?;
+
+    let timestamp = Local::now().format("%Y-%m-%dT%H-%M-%S");
+    let path = logs_dir.join(format!("crash-report-{timestamp}.txt"));
+    std::fs::write(&path, report)?;
+    Ok(path)
+}
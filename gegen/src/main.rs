@@ -1,23 +1,46 @@
+mod clock;
+mod config;
 mod data_fetch;
+mod favourites;
 mod logging;
 mod state;
+#[cfg(test)]
+mod test_utils;
 mod ui;
 
-use color_eyre::Result;
+use color_eyre::{Result, config::HookBuilder};
+use config::{Config, ConfigWatcher};
 use crossterm::event::{self};
 use dashmap::DashMap;
+use favourites::Favourites;
 use ratatui::DefaultTerminal;
 use state::{PageRenderStates, State};
-use std::{sync::Arc, thread::JoinHandle, time::Duration};
+use std::{
+    sync::{Arc, OnceLock},
+    thread::JoinHandle,
+    time::Duration,
+};
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
 pub(crate) const GEGEN_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// the report of a panic on a background thread, the main loop stops when it's set so the
+/// terminal can be restored before the report is printed
+static BACKGROUND_PANIC: OnceLock<String> = OnceLock::new();
+
 fn main() -> Result<()> {
-    color_eyre::install()?;
+    install_hooks()?;
+
+    let config_path = Config::path();
+    let (config, config_error) = match config_path.as_deref().map(Config::load) {
+        Some(Ok(config)) => (config, None),
+        Some(Err(err)) => (Config::default(), Some(err.to_string())),
+        None => (Config::default(), None),
+    };
+
     let terminal = ratatui::init();
 
-    let file_appender = logging::create_file_appender();
+    let file_appender = logging::create_file_appender(config.logging.max_log_files);
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
 
     let env_filter = EnvFilter::from_default_env().add_directive("gegen=debug".parse().unwrap());
@@ -35,17 +58,91 @@ fn main() -> Result<()> {
 
     let (sender, recv) = crossbeam::channel::unbounded();
 
-    let app_state = State::new(data.clone(), sender);
+    if let Some(err) = &config_error {
+        tracing::error!("failed to load config, using the default config: {err}");
+    }
+
+    let favourites_path = Favourites::path();
+    let favourites = match favourites_path.as_deref().map(Favourites::load) {
+        Some(Ok(favourites)) => favourites,
+        Some(Err(err)) => {
+            tracing::error!("failed to load favourites: {err}");
+            Favourites::default()
+        }
+        None => Favourites::default(),
+    };
+
+    let fetch_delay = config.fetch_delay();
+    let config_watcher = config_path.map(ConfigWatcher::new);
+    let app_state = State::new(
+        data.clone(),
+        sender,
+        config,
+        config_error,
+        config_watcher,
+        favourites,
+        favourites_path,
+    );
 
     let render_state = PageRenderStates::default();
 
-    let data_join_handle = data_fetch::run_data_fetch(&data, app_state.today, recv);
+    let data_join_handle = data_fetch::run_data_fetch(
+        &data,
+        &app_state.data_version,
+        app_state.today,
+        recv,
+        fetch_delay,
+    );
+
+    if app_state.mouse_capture
+        && let Err(err) = ui::set_mouse_capture(true)
+    {
+        tracing::error!("failed to enable mouse capture: {err}");
+    }
 
     run(terminal, &data_join_handle, app_state, render_state)?;
 
     Ok(())
 }
 
+/// Installs the color-eyre hooks, with a panic hook that restores the terminal before anything is
+/// printed and writes a crash report to the logs directory. A panic on a background thread stops
+/// the main loop, which restores the terminal and prints the report
+fn install_hooks() -> Result<()> {
+    let (panic_hook, eyre_hook) = HookBuilder::default().into_hooks();
+    let (crash_report_hook, _) = HookBuilder::blank().into_hooks();
+    eyre_hook.install()?;
+
+    std::panic::set_hook(Box::new(move |panic_info| {
+        // the UI is still drawing when a background thread panics, so only the main thread can
+        // restore the terminal
+        let on_main_thread = std::thread::current().name() == Some("main");
+        if on_main_thread {
+            let _ = ui::set_mouse_capture(false);
+            ratatui::restore();
+        }
+
+        let crash_report = crash_report_hook.panic_report(panic_info).to_string();
+        tracing::error!("gegen panicked: {crash_report}");
+        let crash_report_message = match logging::write_crash_report(&crash_report) {
+            Ok(path) => format!("crash report written to {}", path.display()),
+            Err(err) => format!("failed to write crash report: {err}"),
+        };
+        let report = format!(
+            "{crash_report_message}\n{}",
+            panic_hook.panic_report(panic_info)
+        );
+
+        if on_main_thread {
+            eprintln!("{report}");
+        } else {
+            let _ = BACKGROUND_PANIC.set(report);
+        }
+    }));
+
+    Ok(())
+}
+
 fn run(
     mut terminal: DefaultTerminal,
     data_join_handle: &JoinHandle<()>,
@@ -53,7 +150,10 @@ fn run(
     mut page_states: PageRenderStates,
 ) -> Result<()> {
     loop {
-        if app_state.should_quit || data_join_handle.is_finished() {
+        if app_state.should_quit
+            || data_join_handle.is_finished()
+            || BACKGROUND_PANIC.get().is_some()
+        {
             break;
         }
 
@@ -68,6 +168,12 @@ fn run(
         }
     }
 
+    ui::set_mouse_capture(false)?;
     ratatui::restore();
+
+    if let Some(report) = BACKGROUND_PANIC.get() {
+        eprintln!("{report}");
+        return Err(color_eyre::eyre::eyre!("a background thread panicked"));
+    }
     Ok(())
 }
@@ -1,47 +1,231 @@
 use std::{
-    sync::Arc,
-    time::{Duration, SystemTime},
+    collections::HashSet,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Instant,
 };
 
-use chrono::{Days, Local, NaiveDate};
+use chrono::{DateTime, Days, NaiveDate, TimeDelta, Utc};
 use crossbeam::channel::Sender;
-use dashmap::DashMap;
-use gegen_data::types::{LiveScoresResponse, Match};
-use itertools::Itertools;
-use ratatui::widgets::TableState;
+use dashmap::{DashMap, mapref::one::Ref};
+use gegen_data::{
+    competition, schedule,
+    team::{self, TeamMatches},
+    types::{Competition, CompetitionId, LiveScoresResponse, Match, MatchId, Side, TeamId},
+};
+use ratatui::{
+    layout::Rect,
+    widgets::{ListState, TableState},
+};
+
+use crate::{
+    clock::Clock,
+    config::{Config, ConfigWatcher},
+    data_fetch::DataRequest,
+    favourites::Favourites,
+    ui::{
+        date_picker::{CalendarState, DatePromptState},
+        keymap::Keymap,
+        search::SearchState,
+        theme::Theme,
+    },
+};
+
+/// name of the tab with the matches of favourite teams and competitions
+pub(crate) const FAVOURITES_TAB: &str = "★ Favourites";
 
 pub(crate) type LiveData = Arc<DashMap<NaiveDate, LiveScoresResponse>>;
 
+/// Counts the days the data fetch thread has inserted into [`LiveData`], anything worked out from
+/// the data is stale once it changes
+pub(crate) type DataVersion = Arc<AtomicUsize>;
+
 pub(crate) enum Page {
     Matches(NaiveDate),
     MatchOverview {
         date: NaiveDate,
-        match_id: String,
+        match_id: MatchId,
+        competition_name: String,
+    },
+    /// seven days from `start` for one competition, or the favourites
+    Week {
+        start: NaiveDate,
         competition_name: String,
     },
+    /// a team's results and fixtures across the days that have been loaded
+    Team {
+        team_id: TeamId,
+        team_name: String,
+    },
+    /// a competition's fixtures or results for the days either side of today
+    Competition {
+        competition_id: CompetitionId,
+        competition_name: String,
+    },
+    /// a league table worked out from the competition's results that have been loaded
+    Standings {
+        competition_id: CompetitionId,
+        competition_name: String,
+        /// the teams of the match the page was opened from
+        highlighted: Vec<TeamId>,
+    },
 }
 
+/// number of days shown on the week page
+pub(crate) const WEEK_LENGTH: u64 = 7;
+
 pub(crate) struct State {
     pub(crate) data: LiveData,
-    tick_rate: Duration,
-    last_tick: SystemTime,
+    pub(crate) data_version: DataVersion,
+    pub(crate) config: Config,
+    pub(crate) theme: Theme,
+    pub(crate) keymap: Keymap,
+    pub(crate) favourites: Favourites,
+    favourites_path: Option<PathBuf>,
+    config_watcher: Option<ConfigWatcher>,
+    /// set when the config file couldn't be loaded, shown in a pop up until dismissed
+    pub(crate) config_error: Option<String>,
+    last_tick: Instant,
     pub(crate) current_page: Page,
+    /// the pages that were left to open the current one, most recent last
+    history: Vec<Page>,
     pub(crate) should_quit: bool,
-    sender: Sender<NaiveDate>,
+    sender: Sender<DataRequest>,
+    /// today's date in the display timezone, the live scores are stored under this date
     pub(crate) today: NaiveDate,
+    /// the timezone and format times and dates are shown in
+    pub(crate) clock: Clock,
     pub(crate) show_metadata_pop_up: bool,
     pub(crate) show_key_bind_pop_up: bool,
+    /// when mouse capture is off the terminal handles the mouse, so text can be selected
+    pub(crate) mouse_capture: bool,
+    /// when hidden the competitions are shown as compact tabs instead
+    pub(crate) show_sidebar: bool,
+    /// when on the matches page shows the last and next 24 hours rather than a calendar day
+    pub(crate) rolling_window: bool,
 }
 
 #[derive(Default)]
 pub(crate) struct PageRenderStates {
     pub(crate) live_scores: LiveScoresPageState,
     pub(crate) match_overview: MatchOverviewRenderState,
+    pub(crate) anomalies: AnomalyLog,
+    /// set while the search pop up is open
+    pub(crate) search: Option<SearchState>,
+    /// set while the go to date prompt is open
+    pub(crate) date_prompt: Option<DatePromptState>,
+    /// set while the calendar pop up is open
+    pub(crate) calendar: Option<CalendarState>,
+    pub(crate) week: WeekPageState,
+    pub(crate) team: TeamPageState,
+    pub(crate) competition: CompetitionPageState,
+    pub(crate) standings: StandingsPageState,
+}
+
+#[derive(Default)]
+pub(crate) struct WeekPageState {
+    /// lines scrolled down, shared by every day so they stay level
+    pub(crate) scroll: u16,
+}
+
+pub(crate) struct TeamPageState {
+    pub(crate) table_state: TableState,
+    /// days either side of today that are fetched for the team's matches
+    pub(crate) days: u64,
+}
+
+impl Default for TeamPageState {
+    fn default() -> Self {
+        Self {
+            table_state: TableState::default(),
+            days: WEEK_LENGTH,
+        }
+    }
+}
+
+pub(crate) enum TeamRow {
+    Heading(&'static str),
+    Match(Box<Match>),
+}
+
+#[derive(Default)]
+pub(crate) struct CompetitionPageState {
+    pub(crate) table_state: TableState,
+    pub(crate) view: CompetitionView,
+}
+
+/// Which of a competition's matches the competition page lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum CompetitionView {
+    /// matches in play and yet to start, soonest first
+    #[default]
+    Fixtures,
+    /// finished matches, most recent first
+    Results,
+}
+
+impl CompetitionPageState {
+    pub(crate) fn toggle_results(&mut self) {
+        self.view = match self.view {
+            CompetitionView::Fixtures => CompetitionView::Results,
+            CompetitionView::Results => CompetitionView::Fixtures,
+        };
+        self.table_state = Default::default();
+    }
+}
+
+pub(crate) struct StandingsPageState {
+    pub(crate) table_state: TableState,
+    /// days of results before today that are fetched for the table
+    pub(crate) days: u64,
+}
+
+impl Default for StandingsPageState {
+    fn default() -> Self {
+        Self {
+            table_state: TableState::default(),
+            days: 4 * WEEK_LENGTH,
+        }
+    }
+}
+
+pub(crate) enum CompetitionRow {
+    Day(NaiveDate),
+    Match(Box<Match>),
+}
+
+/// Problems with the data for a match that can be rendered around but are worth knowing about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Anomaly {
+    UnknownPeriod(usize),
+    UnknownStatus,
+    MissingScore,
+}
+
+/// Records which anomalies have already been logged, pages are redrawn on every tick so without
+/// this the logs would be flooded with the same anomaly
+#[derive(Default)]
+pub(crate) struct AnomalyLog {
+    logged: HashSet<(MatchId, Anomaly)>,
+}
+
+impl AnomalyLog {
+    pub(crate) fn log_once(&mut self, match_data: &Match, anomaly: Anomaly) {
+        if self.logged.insert((match_data.id.clone(), anomaly)) {
+            tracing::warn!(match_id = %match_data.id, "anomaly in match data {anomaly:?}: {match_data:?}");
+        }
+    }
 }
 
 #[derive(Default)]
 pub(crate) struct MatchOverviewRenderState {
     pub(crate) throbber_state: throbber_widgets_tui::ThrobberState,
+    pub(crate) events_table_state: TableState,
+    /// where the events table was last drawn, used to scroll it with the mouse
+    pub(crate) events_area: Rect,
 }
 
 #[derive(Default)]
@@ -49,27 +233,91 @@ pub(crate) struct LiveScoresPageState {
     pub(crate) throbber_state: throbber_widgets_tui::ThrobberState,
     pub(crate) selected_tab: usize,
     pub(crate) table_state: TableState,
+    /// the areas below are from the last draw and are used to handle mouse clicks
+    pub(crate) tab_areas: Vec<Rect>,
+    /// the rows of the fixtures table, excluding its border
+    pub(crate) table_area: Rect,
+    pub(crate) date_nav: DateNavAreas,
+    pub(crate) sidebar_state: ListState,
+    /// the inside of the sidebar, empty when the compact tabs are shown instead
+    pub(crate) sidebar_area: Rect,
+    /// the tab for each item in the sidebar, country headers don't have one
+    pub(crate) sidebar_tabs: Vec<Option<usize>>,
+    pub(crate) view: MatchesView,
+}
+
+/// What the matches page lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum MatchesView {
+    /// the matches of the selected competition
+    #[default]
+    Competitions,
+    /// every match in play, across all competitions
+    AllLive,
+}
+
+pub(crate) enum AllLiveRow {
+    Competition(String),
+    Match(Box<Match>),
+}
+
+/// The clickable arrows either side of the date in the page title
+#[derive(Default, Clone, Copy)]
+pub(crate) struct DateNavAreas {
+    pub(crate) previous_day: Rect,
+    pub(crate) next_day: Rect,
 }
 
 impl LiveScoresPageState {
+    pub(crate) fn toggle_all_live(&mut self) {
+        self.view = match self.view {
+            MatchesView::Competitions => MatchesView::AllLive,
+            MatchesView::AllLive => MatchesView::Competitions,
+        };
+        self.table_state = Default::default();
+    }
+
     pub(crate) fn reset_scroll_state(&mut self) {
         self.selected_tab = 0;
     }
 }
 
 impl State {
-    pub(crate) fn new(data: LiveData, sender: Sender<NaiveDate>) -> Self {
-        let today = get_todays_date();
+    pub(crate) fn new(
+        data: LiveData,
+        sender: Sender<DataRequest>,
+        config: Config,
+        config_error: Option<String>,
+        config_watcher: Option<ConfigWatcher>,
+        favourites: Favourites,
+        favourites_path: Option<PathBuf>,
+    ) -> Self {
+        let clock = config.clock();
+        let config_mouse_capture = config.general.mouse;
+        let config_show_sidebar = config.general.sidebar;
         Self {
             data,
-            tick_rate: Duration::from_millis(150),
-            last_tick: SystemTime::now(),
-            current_page: Page::Matches(today),
+            data_version: Default::default(),
+            theme: config.theme(),
+            // the keymap is checked when the config is loaded
+            keymap: config.keymap().unwrap_or_default(),
+            config,
+            config_watcher,
+            favourites,
+            favourites_path,
+            config_error,
+            last_tick: Instant::now(),
+            current_page: Page::Matches(clock.today()),
+            history: Vec::new(),
             should_quit: false,
             sender,
-            today,
+            today: clock.today(),
+            clock,
             show_metadata_pop_up: false,
             show_key_bind_pop_up: false,
+            mouse_capture: config_mouse_capture,
+            show_sidebar: config_show_sidebar,
+            rolling_window: false,
         }
     }
 
@@ -78,14 +326,30 @@ impl State {
     }
 
     pub(crate) fn previous_row(&mut self, render_state: &mut PageRenderStates) {
-        render_state.live_scores.table_state.select_previous();
+        match self.current_page {
+            Page::Week { .. } => {
+                render_state.week.scroll = render_state.week.scroll.saturating_sub(1);
+            }
+            Page::Team { .. } => render_state.team.table_state.select_previous(),
+            Page::Competition { .. } => render_state.competition.table_state.select_previous(),
+            Page::Standings { .. } => render_state.standings.table_state.select_previous(),
+            _ => render_state.live_scores.table_state.select_previous(),
+        }
     }
 
     pub(crate) fn next_row(&mut self, render_state: &mut PageRenderStates) {
-        render_state.live_scores.table_state.select_next();
+        match self.current_page {
+            Page::Week { .. } => {
+                render_state.week.scroll = render_state.week.scroll.saturating_add(1);
+            }
+            Page::Team { .. } => render_state.team.table_state.select_next(),
+            Page::Competition { .. } => render_state.competition.table_state.select_next(),
+            Page::Standings { .. } => render_state.standings.table_state.select_next(),
+            _ => render_state.live_scores.table_state.select_next(),
+        }
     }
 
-    pub(crate) fn selected_tab(&self, render_state: &mut PageRenderStates) -> usize {
+    pub(crate) fn selected_tab(&self, render_state: &PageRenderStates) -> usize {
         render_state.live_scores.selected_tab
     }
 
@@ -97,14 +361,77 @@ impl State {
         self.show_key_bind_pop_up = !self.show_key_bind_pop_up;
     }
 
+    pub(crate) fn toggle_sidebar(&mut self) {
+        self.show_sidebar = !self.show_sidebar;
+    }
+
+    pub(crate) fn next_competition(&mut self, render_state: &mut PageRenderStates) {
+        let Some(grouped_data) = self.get_grouped_data() else {
+            return;
+        };
+        // subtract one here because tabs are zero indexed
+        let max_no_tabs = grouped_data.len().saturating_sub(1);
+
+        render_state.live_scores.selected_tab = render_state
+            .live_scores
+            .selected_tab
+            .saturating_add(1)
+            .min(max_no_tabs);
+        render_state.live_scores.table_state = Default::default();
+    }
+
+    pub(crate) fn previous_competition(&mut self, render_state: &mut PageRenderStates) {
+        render_state.live_scores.selected_tab =
+            render_state.live_scores.selected_tab.saturating_sub(1);
+
+        render_state.live_scores.table_state = Default::default();
+    }
+
+    pub(crate) fn toggle_mouse_capture(&mut self) {
+        self.mouse_capture = !self.mouse_capture;
+        if let Err(err) = crate::ui::set_mouse_capture(self.mouse_capture) {
+            tracing::error!("failed to toggle mouse capture: {err}");
+        }
+    }
+
     pub(crate) fn reset_to_today(&mut self) {
         self.current_page = Page::Matches(self.today);
+        self.rolling_window = false;
+    }
+
+    /// switches to the next timezone in the config file, the matches page stays on the same date
+    pub(crate) fn cycle_timezone(&mut self) {
+        let timezones = &self.config.time.timezones;
+        let next = timezones
+            .iter()
+            .position(|timezone| *timezone == self.clock.timezone)
+            .map_or(0, |current| (current + 1) % timezones.len());
+        if let Some(timezone) = timezones.get(next) {
+            self.clock.timezone = *timezone;
+            self.update_today();
+        }
+    }
+
+    pub(crate) fn toggle_clock_format(&mut self) {
+        self.clock.format = self.clock.format.toggled();
+    }
+
+    /// switches the matches page between calendar days and the 24 hours either side of now
+    pub(crate) fn toggle_rolling_window(&mut self, render_state: &mut PageRenderStates) {
+        if !matches!(self.current_page, Page::Matches(_)) {
+            return;
+        }
+        self.rolling_window = !self.rolling_window;
+        self.current_page = Page::Matches(self.today);
+        render_state.live_scores.reset_scroll_state();
+        render_state.live_scores.table_state = Default::default();
     }
 
     pub(crate) fn previous_day(&mut self, render_state: &mut PageRenderStates) {
         let Page::Matches(current_page_date) = self.current_page else {
             return;
         };
+        self.rolling_window = false;
 
         let next_day = current_page_date.checked_sub_days(Days::new(1)).unwrap();
         self.current_page = Page::Matches(next_day);
@@ -115,6 +442,7 @@ impl State {
         let Page::Matches(current_page_date) = self.current_page else {
             return;
         };
+        self.rolling_window = false;
 
         let next_day = current_page_date.checked_add_days(Days::new(1)).unwrap();
         self.current_page = Page::Matches(next_day);
@@ -122,9 +450,186 @@ impl State {
         render_state.live_scores.reset_scroll_state();
     }
 
+    /// shows the matches for any date, fetching them if they haven't been loaded yet
+    pub(crate) fn go_to_date(&mut self, date: NaiveDate, render_state: &mut PageRenderStates) {
+        self.current_page = Page::Matches(date);
+        self.rolling_window = false;
+        render_state.live_scores.reset_scroll_state();
+        render_state.live_scores.table_state = Default::default();
+    }
+
+    /// shows the week from the current date for the selected competition, or the favourites when
+    /// the favourites tab is selected
+    pub(crate) fn open_week(&mut self, render_state: &mut PageRenderStates) {
+        let Page::Matches(date) = self.current_page else {
+            return;
+        };
+        let Some(grouped_data) = self.get_grouped_data() else {
+            return;
+        };
+        let Some((competition_name, _)) = grouped_data.get(self.selected_tab(render_state)) else {
+            return;
+        };
+
+        render_state.week = Default::default();
+        self.open_page(
+            Page::Week {
+                start: date,
+                competition_name: competition_name.clone(),
+            },
+            render_state,
+        );
+    }
+
+    /// shows the results and fixtures of the home or away team of the current match
+    pub(crate) fn open_team_page(&mut self, side: Side, render_state: &mut PageRenderStates) {
+        let Some(match_data) = self.current_match(render_state) else {
+            return;
+        };
+        let team = match side {
+            Side::Home => match_data.home,
+            Side::Away => match_data.away,
+        };
+        // teams that are still to be confirmed don't have an id
+        let Some(team_id) = team.id else {
+            return;
+        };
+
+        render_state.team = Default::default();
+        self.open_page(
+            Page::Team {
+                team_id,
+                team_name: team.name.unwrap_or_default(),
+            },
+            render_state,
+        );
+    }
+
+    /// shows the fixtures and results of the current competition
+    pub(crate) fn open_competition_page(&mut self, render_state: &mut PageRenderStates) {
+        let Some(competition) = self.current_competition(render_state) else {
+            return;
+        };
+
+        render_state.competition = Default::default();
+        self.open_page(
+            Page::Competition {
+                competition_name: competition_name(&competition),
+                competition_id: competition.id,
+            },
+            render_state,
+        );
+    }
+
+    /// shows the league table of the current competition, with the teams of the current match
+    /// highlighted
+    pub(crate) fn open_standings_page(&mut self, render_state: &mut PageRenderStates) {
+        let Some(competition) = self.current_competition(render_state) else {
+            return;
+        };
+        let highlighted = self
+            .current_match(render_state)
+            .into_iter()
+            .flat_map(|match_data| [match_data.home.id, match_data.away.id])
+            .flatten()
+            .collect();
+
+        render_state.standings = Default::default();
+        self.open_page(
+            Page::Standings {
+                competition_name: competition_name(&competition),
+                competition_id: competition.id,
+                highlighted,
+            },
+            render_state,
+        );
+    }
+
+    /// The competition of the current match. Without one it's the selected competition on the
+    /// matches page or the competition whose page is open
+    fn current_competition(&self, render_state: &PageRenderStates) -> Option<Competition> {
+        if let Some(match_data) = self.current_match(render_state) {
+            return Some(match_data.comp);
+        }
+        let first_match = match &self.current_page {
+            Page::Matches(_) => {
+                let (competition_name, matches) = self
+                    .get_grouped_data()?
+                    .into_iter()
+                    .nth(self.selected_tab(render_state))?;
+                if competition_name == FAVOURITES_TAB {
+                    return None;
+                }
+                matches.into_iter().next()
+            }
+            Page::Competition { competition_id, .. } => {
+                self.competition_matches(competition_id).into_iter().next()
+            }
+            _ => None,
+        };
+        first_match.map(|match_data| match_data.comp)
+    }
+
+    /// fetches another week of days for the team or standings page
+    pub(crate) fn load_more_days(&mut self, render_state: &mut PageRenderStates) {
+        match self.current_page {
+            Page::Team { .. } => {
+                render_state.team.days = render_state.team.days.saturating_add(WEEK_LENGTH);
+            }
+            Page::Standings { .. } => {
+                render_state.standings.days =
+                    render_state.standings.days.saturating_add(WEEK_LENGTH);
+            }
+            _ => return,
+        }
+        self.fetch_data_for_page(render_state);
+    }
+
+    /// Opens `page`, the current page is remembered so [`State::go_back`] can return to it
+    pub(crate) fn open_page(&mut self, page: Page, render_state: &PageRenderStates) {
+        let previous = std::mem::replace(&mut self.current_page, page);
+        self.history.push(previous);
+        self.fetch_data_for_page(render_state);
+    }
+
+    /// returns to the page the current one was opened from, or the matches page if there isn't one
+    pub(crate) fn go_back(&mut self, render_state: &PageRenderStates) {
+        self.current_page = self
+            .history
+            .pop()
+            .unwrap_or_else(|| Page::Matches(self.current_date()));
+        self.fetch_data_for_page(render_state);
+    }
+
+    pub(crate) fn next_week(&mut self, render_state: &mut PageRenderStates) {
+        self.move_week(render_state, |start| {
+            start.checked_add_days(Days::new(WEEK_LENGTH))
+        });
+    }
+
+    pub(crate) fn previous_week(&mut self, render_state: &mut PageRenderStates) {
+        self.move_week(render_state, |start| {
+            start.checked_sub_days(Days::new(WEEK_LENGTH))
+        });
+    }
+
+    fn move_week(
+        &mut self,
+        render_state: &mut PageRenderStates,
+        move_start: impl Fn(NaiveDate) -> Option<NaiveDate>,
+    ) {
+        if let Page::Week { start, .. } = &mut self.current_page
+            && let Some(new_start) = move_start(*start)
+        {
+            *start = new_start;
+            render_state.week = Default::default();
+            self.fetch_data_for_page(render_state);
+        }
+    }
+
     pub(crate) fn should_draw(&mut self) -> bool {
-        if self.last_tick.elapsed().unwrap() > self.tick_rate {
-            self.last_tick = SystemTime::now();
+        if self.last_tick.elapsed() > self.config.tick_rate() {
+            self.last_tick = Instant::now();
             true
         } else {
             false
@@ -133,41 +638,763 @@ impl State {
 
     pub(crate) fn on_tick(&mut self, render_state: &mut PageRenderStates) {
         render_state.live_scores.throbber_state.calc_next();
-        self.today = get_todays_date();
+        self.update_today();
+        self.reload_config_if_changed();
     }
 
-    pub(crate) fn fetch_data_for_date(&self, date: NaiveDate) {
-        if date == self.today {
+    /// Works out today's date again, the data fetch thread is told when it changes so the live
+    /// scores are stored under the same date they're looked up by
+    fn update_today(&mut self) {
+        let today = self.clock.today();
+        if today == self.today {
             return;
         }
-        if let Err(err) = self.sender.try_send(date) {
-            tracing::error!("failed to send date to data fetch thread: {err}")
+        self.today = today;
+        if let Err(err) = self.sender.try_send(DataRequest::SetLiveDate(today)) {
+            tracing::error!("failed to send live date to data fetch thread: {err}")
         }
     }
 
-    pub(crate) fn get_grouped_data(&self) -> Option<Vec<(String, Vec<Match>)>> {
-        let date = match self.current_page {
+    pub(crate) fn dismiss_config_error(&mut self) {
+        self.config_error = None;
+    }
+
+    fn reload_config_if_changed(&mut self) {
+        let Some(reloaded) = self.config_watcher.as_mut().and_then(ConfigWatcher::poll) else {
+            return;
+        };
+
+        match reloaded {
+            Ok(config) => {
+                if config.fetch_delay() != self.config.fetch_delay()
+                    && let Err(err) = self
+                        .sender
+                        .try_send(DataRequest::SetFetchDelay(config.fetch_delay()))
+                {
+                    tracing::error!("failed to send fetch delay to data fetch thread: {err}")
+                }
+                self.theme = config.theme();
+                self.keymap = config.keymap().unwrap_or_default();
+                // keep any timezone or clock switched to while running unless the config changed
+                if config.time != self.config.time {
+                    self.clock = config.clock();
+                    self.update_today();
+                }
+                self.config = config;
+                self.config_error = None;
+            }
+            Err(err) => {
+                tracing::error!("failed to reload config: {err}");
+                self.config_error = Some(err.to_string());
+            }
+        }
+    }
+
+    /// see [`DataVersion`]
+    pub(crate) fn data_version(&self) -> usize {
+        self.data_version.load(Ordering::Relaxed)
+    }
+
+    /// whether every date from `first` to `last` has been loaded
+    pub(crate) fn is_range_loaded(&self, first: NaiveDate, last: NaiveDate) -> bool {
+        first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .all(|date| date == self.today || self.data.contains_key(&date))
+    }
+
+    /// Fetches the days the current page shows that haven't been loaded yet. Pages that show more
+    /// than one day ask for them when they're opened or their days change rather than when
+    /// they're drawn, the matches page fetches its own day, see
+    /// [`State::fetch_data_for_matches_page`]
+    fn fetch_data_for_page(&self, render_state: &PageRenderStates) {
+        let today = self.today;
+        let (first, last) = match &self.current_page {
+            Page::Week { start, .. } => {
+                let last = start
+                    .checked_add_days(Days::new(WEEK_LENGTH - 1))
+                    .unwrap_or(*start);
+                // matches kicking off on the first and last days can be listed under the API days
+                // either side
+                (
+                    start.pred_opt().unwrap_or(*start),
+                    last.succ_opt().unwrap_or(last),
+                )
+            }
+            Page::Team { .. } => {
+                let days = Days::new(render_state.team.days);
+                (
+                    today.checked_sub_days(days).unwrap_or(today),
+                    today.checked_add_days(days).unwrap_or(today),
+                )
+            }
+            Page::Competition { .. } => {
+                let (first, last) = self.competition_window();
+                (
+                    first.pred_opt().unwrap_or(first),
+                    last.succ_opt().unwrap_or(last),
+                )
+            }
+            Page::Standings { .. } => {
+                let days = Days::new(render_state.standings.days);
+                (today.checked_sub_days(days).unwrap_or(today), today)
+            }
+            Page::Matches(_) | Page::MatchOverview { .. } => return,
+        };
+        self.fetch_data_for_range(first, last);
+    }
+
+    /// fetches every date from `first` to `last` that hasn't been loaded yet
+    fn fetch_data_for_range(&self, first: NaiveDate, last: NaiveDate) {
+        if self.is_range_loaded(first, last) {
+            return;
+        }
+        if let Err(err) = self.sender.try_send(DataRequest::DateRange(first, last)) {
+            tracing::error!("failed to send date range to data fetch thread: {err}")
+        }
+    }
+
+    pub(crate) fn current_date(&self) -> NaiveDate {
+        match self.current_page {
             Page::Matches(date) => date,
             Page::MatchOverview { date, .. } => date,
+            Page::Week { start, .. } => start,
+            Page::Team { .. } | Page::Competition { .. } | Page::Standings { .. } => self.today,
+        }
+    }
+
+    /// dates that have been fetched, in order
+    pub(crate) fn loaded_dates(&self) -> Vec<NaiveDate> {
+        let mut dates = self
+            .data
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        dates.sort();
+        dates
+    }
+
+    /// the number of visible matches on a date, `None` if the date hasn't been loaded
+    pub(crate) fn match_count(&self, date: NaiveDate) -> Option<usize> {
+        let matches = self.matches_on(date)?;
+        Some(
+            matches
+                .iter()
+                .filter(|m| !self.config.competitions.is_hidden(&m.comp))
+                .count(),
+        )
+    }
+
+    /// Matches for the current date, or the last and next 24 hours when the rolling window is
+    /// on, grouped by competition with a favourites tab first when any of the matches involve a
+    /// favourite team or competition. Hidden competitions are left out and the rest are ordered
+    /// by the priority in the config file
+    pub(crate) fn get_grouped_data(&self) -> Option<Vec<(String, Vec<Match>)>> {
+        match self.current_page {
+            Page::Matches(_) if self.rolling_window => {
+                Some(self.group_matches(self.rolling_window_matches()?))
+            }
+            _ => self.grouped_data_for(self.current_date()),
+        }
+    }
+
+    /// see [`State::get_grouped_data`]
+    pub(crate) fn grouped_data_for(&self, date: NaiveDate) -> Option<Vec<(String, Vec<Match>)>> {
+        Some(self.group_matches(self.matches_on(date)?))
+    }
+
+    /// Matches kicking off on `date` locally, which can be listed under the API days either side
+    /// of it. `None` until the API day itself has been loaded, the days either side are added as
+    /// they arrive
+    fn matches_on(&self, date: NaiveDate) -> Option<Vec<Match>> {
+        if !self.data.contains_key(&date) {
+            return None;
+        }
+        let api_days = self.loaded_api_days(schedule::api_dates_for(date));
+        Some(self.clock.matches_on_date(api_days, date))
+    }
+
+    /// Matches kicking off in the 24 hours either side of now, `None` until the live scores have
+    /// loaded
+    fn rolling_window_matches(&self) -> Option<Vec<Match>> {
+        if !self.data.contains_key(&self.today) {
+            return None;
+        }
+        let (start, end) = rolling_window();
+        let mut api_dates = schedule::api_dates_between(start, end);
+        // today's live scores are the most up to date, so they go first
+        api_dates.sort_by_key(|date| *date != self.today);
+        let api_days = self.loaded_api_days(api_dates);
+        Some(schedule::matches_between(api_days, start, end))
+    }
+
+    /// The API days that have been loaded, in the order given. Each day is read out of the map as
+    /// it's needed rather than cloned
+    fn loaded_api_days(
+        &self,
+        api_dates: impl IntoIterator<Item = NaiveDate>,
+    ) -> impl Iterator<Item = Ref<'_, NaiveDate, LiveScoresResponse>> {
+        api_dates
+            .into_iter()
+            .filter_map(|date| self.data.get(&date))
+    }
+
+    /// dates that have been fetched with today's live scores first, as they're the most up to date
+    fn loaded_dates_live_first(&self) -> Vec<NaiveDate> {
+        let mut dates = self.loaded_dates();
+        dates.sort_by_key(|date| *date != self.today);
+        dates
+    }
+
+    /// Fetches the API days needed to show the matches on the matches page, see
+    /// [`State::matches_on`]. The date itself is fetched first as nothing is shown until it has
+    /// loaded
+    pub(crate) fn fetch_data_for_matches_page(&self, date: NaiveDate) {
+        let api_dates = if self.rolling_window {
+            let (start, end) = rolling_window();
+            schedule::api_dates_between(start, end)
+        } else {
+            schedule::api_dates_for(date)
+        };
+        if api_dates
+            .iter()
+            .all(|date| *date == self.today || self.data.contains_key(date))
+        {
+            return;
+        }
+        if let Err(err) = self.sender.try_send(DataRequest::Dates(api_dates)) {
+            tracing::error!("failed to send dates to data fetch thread: {err}")
+        }
+    }
+
+    fn group_matches(&self, matches: Vec<Match>) -> Vec<(String, Vec<Match>)> {
+        let competitions_config = &self.config.competitions;
+        let visible_matches = matches
+            .into_iter()
+            .filter(|m| !competitions_config.is_hidden(&m.comp))
+            .collect::<Vec<_>>();
+
+        let mut data_grouped = Vec::new();
+
+        if !self.favourites.is_empty() {
+            let favourites = visible_matches
+                .iter()
+                .filter(|m| self.favourites.contains_match(m))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !favourites.is_empty() {
+                data_grouped.push((FAVOURITES_TAB.to_string(), favourites));
+            }
+        }
+
+        // competitions stay in the order they first appear, matches from the API days either side
+        // come after the day's own matches so they can't be grouped with chunk_by
+        let mut competitions: Vec<(String, Vec<Match>)> = Vec::new();
+        for m in visible_matches {
+            let key = competition_name(&m.comp);
+            match competitions.iter_mut().find(|(name, _)| *name == key) {
+                Some((_, matches)) => matches.push(m),
+                None => competitions.push((key, vec![m])),
+            }
+        }
+        // the sort is stable so competitions without a priority keep the API's order
+        competitions.sort_by_key(|(_, matches)| {
+            matches
+                .first()
+                .and_then(|m| competitions_config.priority(&m.comp))
+                .unwrap_or(usize::MAX)
+        });
+        data_grouped.extend(competitions);
+
+        for (_, matches) in &mut data_grouped {
+            // postponed, suspended etc. matches go after the rest, everything else is in kick
+            // off order
+            matches.sort_by_key(|m| (m.status.is_disrupted(), m.date));
+        }
+        data_grouped
+    }
+
+    /// Matches in play on the current date across every competition, each competition's matches
+    /// are preceded by a row with its name
+    pub(crate) fn all_live_rows(&self) -> Option<Vec<AllLiveRow>> {
+        let grouped_data = self.get_grouped_data()?;
+        let rows = grouped_data
+            .into_iter()
+            .filter(|(competition_name, _)| competition_name != FAVOURITES_TAB)
+            .flat_map(|(competition_name, matches)| {
+                let live_matches = matches
+                    .into_iter()
+                    .filter(Match::is_live)
+                    .map(|match_data| AllLiveRow::Match(Box::new(match_data)))
+                    .collect::<Vec<_>>();
+                if live_matches.is_empty() {
+                    Vec::new()
+                } else {
+                    std::iter::once(AllLiveRow::Competition(competition_name))
+                        .chain(live_matches)
+                        .collect()
+                }
+            })
+            .collect();
+        Some(rows)
+    }
+
+    /// The matches of a team across every day that has been loaded
+    pub(crate) fn team_matches(&self, team_id: &TeamId) -> TeamMatches {
+        let api_dates = self.loaded_dates_live_first();
+        team::team_matches(self.loaded_api_days(api_dates), team_id)
+    }
+
+    /// The team's fixtures followed by its results, each preceded by a heading row
+    pub(crate) fn team_rows(&self, team_id: &TeamId) -> Vec<TeamRow> {
+        let team_matches = self.team_matches(team_id);
+        [
+            ("Fixtures", team_matches.fixtures),
+            ("Results", team_matches.results),
+        ]
+        .into_iter()
+        .filter(|(_, matches)| !matches.is_empty())
+        .flat_map(|(heading, matches)| {
+            std::iter::once(TeamRow::Heading(heading))
+                .chain(matches.into_iter().map(|m| TeamRow::Match(Box::new(m))))
+        })
+        .collect()
+    }
+
+    /// The matches of a competition across every day that has been loaded
+    pub(crate) fn competition_matches(&self, competition_id: &CompetitionId) -> Vec<Match> {
+        let api_dates = self.loaded_dates_live_first();
+        competition::competition_matches(self.loaded_api_days(api_dates), competition_id)
+    }
+
+    /// the first and last days shown on the competition page
+    pub(crate) fn competition_window(&self) -> (NaiveDate, NaiveDate) {
+        let days = Days::new(self.config.competitions.page_days);
+        (
+            self.today.checked_sub_days(days).unwrap_or(self.today),
+            self.today.checked_add_days(days).unwrap_or(self.today),
+        )
+    }
+
+    /// The competition's fixtures or results in the competition window, each day's matches are
+    /// preceded by a row with the date
+    pub(crate) fn competition_rows(
+        &self,
+        competition_id: &CompetitionId,
+        view: CompetitionView,
+    ) -> Vec<CompetitionRow> {
+        let (first, last) = self.competition_window();
+        let matches = self
+            .competition_matches(competition_id)
+            .into_iter()
+            .filter(|m| (first..=last).contains(&self.clock.date_of(m.date)));
+        let matches = match view {
+            CompetitionView::Fixtures => matches
+                .filter(|m| !m.is_finished() || m.status.is_disrupted())
+                .collect::<Vec<_>>(),
+            CompetitionView::Results => matches
+                .filter(|m| m.is_finished() && !m.status.is_disrupted())
+                .rev()
+                .collect(),
         };
-        match self.data.get(&date) {
-            Some(data) => {
-                let mut data_grouped = Vec::new();
-                for (key, chunk) in &data
+
+        let mut rows = Vec::new();
+        let mut previous_date = None;
+        for match_data in matches {
+            let date = self.clock.date_of(match_data.date);
+            if previous_date != Some(date) {
+                rows.push(CompetitionRow::Day(date));
+                previous_date = Some(date);
+            }
+            rows.push(CompetitionRow::Match(Box::new(match_data)));
+        }
+        rows
+    }
+
+    /// Finds a match for the current date by id, regardless of which tab it's in
+    pub(crate) fn find_match(&self, match_id: &MatchId) -> Option<Match> {
+        // today's live scores first as they're the most up to date
+        std::iter::once(self.today)
+            .chain(self.loaded_dates())
+            .find_map(|date| {
+                self.data
+                    .get(&date)?
                     .matches
                     .iter()
-                    .chunk_by(|m| format!("{} - {}", m.comp.country.full_name, m.comp.name,))
+                    .find(|m| &m.id == match_id)
+                    .cloned()
+            })
+    }
+
+    /// the match that is selected on the matches page or shown on the match overview page
+    pub(crate) fn current_match(&self, render_state: &PageRenderStates) -> Option<Match> {
+        match &self.current_page {
+            Page::Matches(_) => {
+                let selected_row = self.selected_row(render_state)?;
+                match render_state.live_scores.view {
+                    MatchesView::Competitions => {
+                        let grouped_data = self.get_grouped_data()?;
+                        let (_, matches) = grouped_data.get(self.selected_tab(render_state))?;
+                        matches.get(selected_row).cloned()
+                    }
+                    MatchesView::AllLive => {
+                        match self.all_live_rows()?.into_iter().nth(selected_row)? {
+                            AllLiveRow::Match(match_data) => Some(*match_data),
+                            AllLiveRow::Competition(_) => None,
+                        }
+                    }
+                }
+            }
+            Page::MatchOverview { match_id, .. } => self.find_match(match_id),
+            Page::Team { team_id, .. } => {
+                let selected_row = render_state.team.table_state.selected()?;
+                match self.team_rows(team_id).into_iter().nth(selected_row)? {
+                    TeamRow::Match(match_data) => Some(*match_data),
+                    TeamRow::Heading(_) => None,
+                }
+            }
+            Page::Competition { competition_id, .. } => {
+                let selected_row = render_state.competition.table_state.selected()?;
+                let view = render_state.competition.view;
+                match self
+                    .competition_rows(competition_id, view)
+                    .into_iter()
+                    .nth(selected_row)?
                 {
-                    // TODO: see if there's a way to remove this clone
-                    data_grouped.push((key, chunk.cloned().collect::<Vec<Match>>()));
+                    CompetitionRow::Match(match_data) => Some(*match_data),
+                    CompetitionRow::Day(_) => None,
                 }
-                Some(data_grouped)
             }
-            None => None,
+            Page::Week { .. } | Page::Standings { .. } => None,
+        }
+    }
+
+    pub(crate) fn toggle_favourite_team(
+        &mut self,
+        side: Side,
+        render_state: &mut PageRenderStates,
+    ) {
+        let Some(match_data) = self.current_match(render_state) else {
+            return;
+        };
+        let team = match side {
+            Side::Home => &match_data.home,
+            Side::Away => &match_data.away,
+        };
+        self.update_favourites(render_state, |favourites| {
+            favourites.toggle_team(team);
+        });
+    }
+
+    pub(crate) fn toggle_favourite_competition(&mut self, render_state: &mut PageRenderStates) {
+        let Some(match_data) = self.current_match(render_state) else {
+            return;
+        };
+        self.update_favourites(render_state, |favourites| {
+            favourites.toggle_competition(&match_data);
+        });
+    }
+
+    /// Applies and saves a change to the favourites, the favourites tab can appear or disappear
+    /// so the selected tab is looked up again by name to keep the same competition selected
+    fn update_favourites(
+        &mut self,
+        render_state: &mut PageRenderStates,
+        update: impl FnOnce(&mut Favourites),
+    ) {
+        let tab_names = |state: &Self| {
+            state
+                .get_grouped_data()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+        let selected_tab_name = tab_names(self)
+            .get(render_state.live_scores.selected_tab)
+            .cloned();
+
+        update(&mut self.favourites);
+
+        if let Some(path) = &self.favourites_path
+            && let Err(err) = self.favourites.save(path)
+        {
+            tracing::error!("failed to save favourites: {err}");
+        }
+
+        let tab_names = tab_names(self);
+        match selected_tab_name.and_then(|name| tab_names.iter().position(|n| *n == name)) {
+            Some(tab) => render_state.live_scores.selected_tab = tab,
+            None => {
+                render_state.live_scores.selected_tab = 0;
+                render_state.live_scores.table_state = Default::default();
+            }
         }
     }
 }
 
-fn get_todays_date() -> NaiveDate {
-    Local::now().date_naive()
+/// the name a competition's matches are grouped under, e.g. `England - Premier League`
+fn competition_name(competition: &Competition) -> String {
+    format!("{} - {}", competition.country.full_name, competition.name)
+}
+
+/// the 24 hours either side of now
+fn rolling_window() -> (DateTime<Utc>, DateTime<Utc>) {
+    let now = Utc::now();
+    (now - TimeDelta::hours(24), now + TimeDelta::hours(24))
+}
+
+#[cfg(test)]
+mod tests {
+    use gegen_data::types::Status;
+
+    use super::*;
+    use crate::test_utils::{
+        build_fixture, build_state, build_state_with_requests, insert_matches, match_day,
+    };
+
+    fn tab_names(grouped_data: &[(String, Vec<Match>)]) -> Vec<&str> {
+        grouped_data.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    fn match_ids(matches: &[Match]) -> Vec<MatchId> {
+        matches.iter().map(|m| m.id.clone()).collect()
+    }
+
+    fn build_live_match(id: &str, country: &str, competition: &str, hours: i64) -> Match {
+        let mut match_data = build_fixture(id, country, competition, hours);
+        match_data.period = 1;
+        match_data.status = Status::Playing;
+        match_data
+    }
+
+    /// the id of the current match with each row selected in turn
+    fn selected_match_ids(
+        state: &State,
+        page_states: &mut PageRenderStates,
+        table_state: impl Fn(&mut PageRenderStates) -> &mut TableState,
+        row_count: usize,
+    ) -> Vec<Option<MatchId>> {
+        (0..row_count)
+            .map(|row| {
+                table_state(page_states).select(Some(row));
+                state.current_match(page_states).map(|m| m.id)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_group_matches_by_priority_without_hidden_competitions() {
+        let mut state = build_state();
+        state.config.competitions.hide = vec!["Italy - Serie B".into()];
+        state.config.competitions.hide_countries = vec!["Netherlands".into()];
+
+        let grouped_data = state.group_matches(vec![
+            build_fixture("championship", "England", "Championship", 0),
+            build_fixture("serie b", "Italy", "Serie B", 1),
+            build_fixture("ligue 1", "France", "Ligue 1", 2),
+            build_fixture("premier league", "England", "Premier League", 3),
+            build_fixture("eredivisie", "Netherlands", "Eredivisie", 4),
+        ]);
+
+        assert_eq!(
+            tab_names(&grouped_data),
+            vec![
+                "England - Premier League",
+                "France - Ligue 1",
+                "England - Championship"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_matches_with_favourites() {
+        let mut state = build_state();
+        state.config.competitions.hide = vec!["France - Ligue 1".into()];
+        let laliga_match = build_fixture("laliga", "Spain", "LaLiga", 1);
+        let ligue_1_match = build_fixture("ligue 1", "France", "Ligue 1", 2);
+        state.favourites.toggle_competition(&laliga_match);
+        state.favourites.toggle_competition(&ligue_1_match);
+
+        let grouped_data = state.group_matches(vec![
+            build_fixture("premier league", "England", "Premier League", 0),
+            laliga_match,
+            ligue_1_match,
+        ]);
+
+        // hidden competitions stay hidden when they're a favourite
+        assert_eq!(
+            tab_names(&grouped_data),
+            vec![FAVOURITES_TAB, "England - Premier League", "Spain - LaLiga"]
+        );
+        assert_eq!(match_ids(&grouped_data[0].1), vec![MatchId::new("laliga")]);
+    }
+
+    #[test]
+    fn test_group_matches_puts_disrupted_matches_last() {
+        let state = build_state();
+        let mut postponed = build_fixture("postponed", "England", "Premier League", 0);
+        postponed.status = Status::Postponed;
+
+        let grouped_data = state.group_matches(vec![
+            postponed,
+            build_fixture("late", "England", "Premier League", 2),
+            build_fixture("early", "England", "Premier League", 1),
+        ]);
+
+        assert_eq!(
+            match_ids(&grouped_data[0].1),
+            vec![
+                MatchId::new("early"),
+                MatchId::new("late"),
+                MatchId::new("postponed")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_all_live_rows() {
+        let mut state = build_state();
+        state.current_page = Page::Matches(match_day());
+        let laliga_match = build_live_match("laliga", "Spain", "LaLiga", 1);
+        // favourites are already in their competition's rows
+        state.favourites.toggle_competition(&laliga_match);
+        insert_matches(
+            &state,
+            match_day(),
+            vec![
+                build_live_match("premier league", "England", "Premier League", 0),
+                build_fixture("fixture", "England", "Premier League", 3),
+                laliga_match,
+                build_fixture("ligue 1", "France", "Ligue 1", 4),
+            ],
+        );
+
+        let mut page_states = PageRenderStates::default();
+        page_states.live_scores.view = MatchesView::AllLive;
+        let row_count = state.all_live_rows().unwrap().len();
+
+        assert_eq!(
+            selected_match_ids(
+                &state,
+                &mut page_states,
+                |page_states| &mut page_states.live_scores.table_state,
+                row_count,
+            ),
+            vec![
+                None,
+                Some(MatchId::new("premier league")),
+                None,
+                Some(MatchId::new("laliga"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_competition_rows() {
+        let mut state = build_state();
+        state.today = match_day();
+        let competition_id = CompetitionId::new("Premier League");
+        state.current_page = Page::Competition {
+            competition_id: competition_id.clone(),
+            competition_name: "England - Premier League".into(),
+        };
+
+        let mut postponed = build_fixture("postponed", "England", "Premier League", -48);
+        postponed.status = Status::Postponed;
+        let mut result = build_fixture("result", "England", "Premier League", -24);
+        result.period = 14;
+        result.status = Status::Played;
+        insert_matches(
+            &state,
+            match_day(),
+            vec![
+                build_fixture("tomorrow", "England", "Premier League", 24),
+                build_fixture("today", "England", "Premier League", 0),
+                build_fixture("other competition", "Spain", "LaLiga", 0),
+                result,
+                postponed,
+                // outside of the days shown on the page
+                build_fixture("next month", "England", "Premier League", 24 * 30),
+            ],
+        );
+
+        let mut page_states = PageRenderStates::default();
+        let fixtures = state.competition_rows(&competition_id, CompetitionView::Fixtures);
+        assert_eq!(
+            selected_match_ids(
+                &state,
+                &mut page_states,
+                |page_states| &mut page_states.competition.table_state,
+                fixtures.len(),
+            ),
+            vec![
+                None,
+                Some(MatchId::new("postponed")),
+                None,
+                Some(MatchId::new("today")),
+                None,
+                Some(MatchId::new("tomorrow"))
+            ]
+        );
+
+        page_states.competition.view = CompetitionView::Results;
+        let results = state.competition_rows(&competition_id, CompetitionView::Results);
+        assert_eq!(
+            selected_match_ids(
+                &state,
+                &mut page_states,
+                |page_states| &mut page_states.competition.table_state,
+                results.len(),
+            ),
+            vec![None, Some(MatchId::new("result"))]
+        );
+    }
+
+    #[test]
+    fn test_standings_days_are_fetched_when_they_change() {
+        let (mut state, requests) = build_state_with_requests();
+        state.today = match_day();
+        let mut page_states = PageRenderStates::default();
+        let requested_range = || match requests.try_recv() {
+            Ok(DataRequest::DateRange(first, last)) => Some((first, last)),
+            _ => None,
+        };
+
+        state.open_page(
+            Page::Standings {
+                competition_id: CompetitionId::new("Premier League"),
+                competition_name: "England - Premier League".into(),
+                highlighted: Vec::new(),
+            },
+            &page_states,
+        );
+        assert_eq!(
+            requested_range(),
+            Some((match_day() - Days::new(28), match_day()))
+        );
+
+        state.load_more_days(&mut page_states);
+        assert_eq!(
+            requested_range(),
+            Some((match_day() - Days::new(35), match_day()))
+        );
+        assert_eq!(requested_range(), None);
+    }
+
+    #[test]
+    fn test_new_live_date_is_sent_to_the_data_fetch_thread() {
+        let (mut state, requests) = build_state_with_requests();
+        state.today = match_day();
+
+        state.on_tick(&mut PageRenderStates::default());
+
+        assert_eq!(state.today, state.clock.today());
+        assert!(matches!(
+            requests.try_recv(),
+            Ok(DataRequest::SetLiveDate(date)) if date == state.today
+        ));
+        state.on_tick(&mut PageRenderStates::default());
+        assert!(requests.try_recv().is_err());
+    }
 }
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use crossbeam::channel::Receiver;
+use dashmap::DashMap;
+use gegen_data::{
+    test_utils::{build_match_at, kickoff_after},
+    types::{CompetitionId, Country, LiveScoresResponse, Match, Status},
+};
+
+use crate::{
+    clock::DisplayTimezone, config::Config, data_fetch::DataRequest, favourites::Favourites,
+    state::State,
+};
+
+/// the date of [`gegen_data::test_utils::kickoff_after`] in UTC
+pub(crate) fn match_day() -> NaiveDate {
+    kickoff_after(0).date_naive()
+}
+
+/// a state with the default config and times shown in UTC, nothing is fetched
+pub(crate) fn build_state() -> State {
+    build_state_with_requests().0
+}
+
+/// [`build_state`] along with the requests it sends to the data fetch thread
+pub(crate) fn build_state_with_requests() -> (State, Receiver<DataRequest>) {
+    let (sender, receiver) = crossbeam::channel::unbounded();
+    let mut state = State::new(
+        Arc::new(DashMap::new()),
+        sender,
+        Config::default(),
+        None,
+        None,
+        Favourites::default(),
+        None,
+    );
+    state.clock.timezone = DisplayTimezone::Named(chrono_tz::UTC);
+    (state, receiver)
+}
+
+/// a fixture kicking off `hours` after the first match of [`match_day`] in a competition of its
+/// own
+pub(crate) fn build_fixture(id: &str, country: &str, competition: &str, hours: i64) -> Match {
+    let kickoff = kickoff_after(0) + chrono::Duration::hours(hours);
+    let mut match_data = build_match_at(id, kickoff, 16, Status::Fixture, &[]);
+    match_data.comp.id = CompetitionId::new(competition);
+    match_data.comp.name = competition.into();
+    match_data.comp.country = Country {
+        id: country.to_lowercase(),
+        full_name: country.into(),
+    };
+    match_data
+}
+
+/// stores `matches` as the API day `date`
+pub(crate) fn insert_matches(state: &State, date: NaiveDate, matches: Vec<Match>) {
+    state.data.insert(date, LiveScoresResponse { matches });
+}
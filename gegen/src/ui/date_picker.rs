@@ -0,0 +1,332 @@
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint},
+    style::{Style, Stylize},
+    text::{Line, Text},
+    widgets::{Block, Cell, Clear, Paragraph, Row, Table},
+};
+
+use crate::{PageRenderStates, state::State};
+
+use super::{
+    centered_area,
+    keymap::{Action, KeyScope},
+};
+
+const DATE_PROMPT_HINT: &str = "e.g. 2025-04-27, -3d, +1w, yesterday or next sat";
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const CALENDAR_CELL_WIDTH: u16 = 6;
+
+/// The text typed into the date prompt, `invalid` is set when it couldn't be understood
+#[derive(Debug, Default)]
+pub(crate) struct DatePromptState {
+    pub(crate) input: String,
+    pub(crate) invalid: bool,
+}
+
+/// The day highlighted in the calendar pop up, the calendar shows its month
+#[derive(Debug)]
+pub(crate) struct CalendarState {
+    pub(crate) selected: NaiveDate,
+}
+
+/// Parses a date typed into the date prompt. Relative dates and weekdays are relative to `today`:
+///
+/// - `2025-04-27`
+/// - `today`, `tomorrow` and `yesterday`
+/// - a number of days, weeks or months such as `-3d`, `+2w` or `-1m`, days when there's no unit
+/// - `sat` or `saturday` for the next saturday, today if it's a saturday, and `next sat` or
+///   `last sat` for the ones after or before today
+pub(crate) fn parse_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+
+    match input.as_str() {
+        "" => return None,
+        "today" => return Some(today),
+        "tomorrow" => return today.checked_add_days(Days::new(1)),
+        "yesterday" => return today.checked_sub_days(Days::new(1)),
+        _ => (),
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    if let Some(date) = parse_relative(&input, today) {
+        return Some(date);
+    }
+
+    let (direction, weekday) = match input.split_once(' ') {
+        Some(("next", weekday)) => (Some(true), weekday),
+        Some(("last" | "prev" | "previous", weekday)) => (Some(false), weekday),
+        Some(_) => return None,
+        None => (None, input.as_str()),
+    };
+    let weekday = weekday.trim().parse::<Weekday>().ok()?;
+
+    let days_until =
+        (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    match direction {
+        None => today.checked_add_days(Days::new(days_until.into())),
+        Some(true) => {
+            let days_until = if days_until == 0 { 7 } else { days_until };
+            today.checked_add_days(Days::new(days_until.into()))
+        }
+        Some(false) => {
+            let days_since = (7 - days_until) % 7;
+            let days_since = if days_since == 0 { 7 } else { days_since };
+            today.checked_sub_days(Days::new(days_since.into()))
+        }
+    }
+}
+
+/// `-3d`, `+2w`, `1m` or `-3`
+fn parse_relative(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (forwards, rest) = match input.strip_prefix('-') {
+        Some(rest) => (false, rest),
+        None => (true, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let unit_start = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (amount, unit) = rest.split_at(unit_start);
+    let amount = amount.parse::<u32>().ok()?;
+
+    match (unit.trim(), forwards) {
+        ("" | "d", true) => today.checked_add_days(Days::new(amount.into())),
+        ("" | "d", false) => today.checked_sub_days(Days::new(amount.into())),
+        ("w", true) => today.checked_add_days(Days::new(u64::from(amount) * 7)),
+        ("w", false) => today.checked_sub_days(Days::new(u64::from(amount) * 7)),
+        ("m", true) => today.checked_add_months(Months::new(amount)),
+        ("m", false) => today.checked_sub_months(Months::new(amount)),
+        _ => None,
+    }
+}
+
+/// Handles a key press while the date prompt is open, keys that aren't bound to a prompt action
+/// are typed into the prompt
+pub(crate) fn process_prompt_key(
+    key: KeyEvent,
+    app_state: &mut State,
+    page_states: &mut PageRenderStates,
+) {
+    let Some(prompt_state) = &mut page_states.date_prompt else {
+        return;
+    };
+
+    match app_state.keymap.action(KeyScope::DatePrompt, key) {
+        Some(Action::Quit) => app_state.should_quit = true,
+        Some(Action::Close) => page_states.date_prompt = None,
+        Some(Action::Confirm) => match parse_date(&prompt_state.input, app_state.today) {
+            Some(date) => {
+                app_state.go_to_date(date, page_states);
+                page_states.date_prompt = None;
+            }
+            None => prompt_state.invalid = true,
+        },
+        Some(Action::DeleteCharacter) => {
+            prompt_state.input.pop();
+            prompt_state.invalid = false;
+        }
+        Some(_) => (),
+        None => {
+            if let (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) =
+                (key.code, key.modifiers)
+            {
+                prompt_state.input.push(c);
+                prompt_state.invalid = false;
+            }
+        }
+    }
+}
+
+/// Handles a key press while the calendar is open, by default the arrow keys or `hjkl` move by a
+/// day or a week, `n` and `p` by a month
+pub(crate) fn process_calendar_key(
+    key: KeyEvent,
+    app_state: &mut State,
+    page_states: &mut PageRenderStates,
+) {
+    let Some(calendar_state) = &mut page_states.calendar else {
+        return;
+    };
+    let selected = calendar_state.selected;
+
+    let new_selection = match app_state.keymap.action(KeyScope::Calendar, key) {
+        Some(Action::Quit) => {
+            app_state.should_quit = true;
+            return;
+        }
+        Some(Action::Close) => {
+            page_states.calendar = None;
+            return;
+        }
+        Some(Action::Confirm) => {
+            app_state.go_to_date(selected, page_states);
+            page_states.calendar = None;
+            return;
+        }
+        Some(Action::CalendarPreviousDay) => selected.checked_sub_days(Days::new(1)),
+        Some(Action::CalendarNextDay) => selected.checked_add_days(Days::new(1)),
+        Some(Action::CalendarPreviousWeek) => selected.checked_sub_days(Days::new(7)),
+        Some(Action::CalendarNextWeek) => selected.checked_add_days(Days::new(7)),
+        Some(Action::PreviousMonth) => selected.checked_sub_months(Months::new(1)),
+        Some(Action::NextMonth) => selected.checked_add_months(Months::new(1)),
+        Some(Action::Today) => Some(app_state.today),
+        _ => None,
+    };
+
+    if let Some(new_selection) = new_selection {
+        calendar_state.selected = new_selection;
+    }
+}
+
+pub(crate) fn draw_date_prompt(
+    frame: &mut Frame,
+    app_state: &State,
+    prompt_state: &DatePromptState,
+) {
+    let block = Block::bordered()
+        .title("Go to date")
+        .title_style(Style::new().fg(app_state.theme.popup_title));
+    let area = centered_area(frame.area(), 60, 5);
+    frame.render_widget(Clear, area);
+
+    let hint = if prompt_state.invalid {
+        Line::from("couldn't understand that date").fg(app_state.theme.warning)
+    } else {
+        Line::from(DATE_PROMPT_HINT).italic()
+    };
+
+    let paragraph = Paragraph::new(vec![
+        Line::from(vec![
+            "> ".bold(),
+            prompt_state.input.clone().into(),
+            "▏".slow_blink(),
+        ]),
+        Line::raw(""),
+        hint,
+    ]);
+    frame.render_widget(paragraph.block(block), area);
+}
+
+/// A month with the number of matches on each day that has been loaded, today is underlined
+pub(crate) fn draw_calendar(frame: &mut Frame, app_state: &State, calendar_state: &CalendarState) {
+    let theme = &app_state.theme;
+    let selected = calendar_state.selected;
+    let first_of_month = selected.with_day(1).expect("every month has a first day");
+
+    let block = Block::bordered()
+        .title(first_of_month.format("%B %Y").to_string())
+        .title_style(Style::new().fg(theme.popup_title))
+        .title_bottom(Line::from("⏎ open · n/p month · t today").italic());
+
+    // 7 columns with a one column gap, a header and up to six weeks of two lines each
+    let width = CALENDAR_CELL_WIDTH * 7 + 6 + 2;
+    let area = centered_area(frame.area(), width, 1 + 6 * 2 + 2);
+    frame.render_widget(Clear, area);
+
+    let header = Row::new(
+        WEEKDAYS.map(|weekday| Cell::new(Text::from(weekday).alignment(Alignment::Center))),
+    )
+    .style(Style::new().fg(theme.title).bold());
+
+    let leading_days = first_of_month.weekday().num_days_from_monday() as usize;
+    let days = first_of_month
+        .iter_days()
+        .take_while(|day| day.month() == selected.month());
+    let cells = std::iter::repeat_n(None, leading_days)
+        .chain(days.map(Some))
+        .map(|day| match day {
+            Some(day) => calendar_cell(app_state, day, selected),
+            None => Cell::new(""),
+        })
+        .collect::<Vec<_>>();
+
+    let rows = cells
+        .chunks(7)
+        .map(|week| Row::new(week.to_vec()).height(2))
+        .collect::<Vec<_>>();
+
+    let table = Table::new(rows, [Constraint::Length(CALENDAR_CELL_WIDTH); 7])
+        .header(header)
+        .block(block);
+
+    frame.render_widget(table, area);
+}
+
+fn calendar_cell(app_state: &State, day: NaiveDate, selected: NaiveDate) -> Cell<'static> {
+    let theme = &app_state.theme;
+
+    let mut day_style = Style::new().bold();
+    if day == app_state.today {
+        day_style = day_style.fg(theme.accent).underlined();
+    }
+
+    let count = match app_state.match_count(day) {
+        Some(count) => Line::from(count.to_string()).fg(theme.title).italic(),
+        None => Line::from("·").dim(),
+    };
+
+    let cell = Cell::new(
+        Text::from(vec![
+            Line::from(day.day().to_string()).style(day_style),
+            count,
+        ])
+        .alignment(Alignment::Center),
+    );
+
+    if day == selected {
+        cell.style(theme.selected_row_style())
+    } else {
+        cell
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_absolute_and_relative_dates() {
+        // a wednesday
+        let today = date(2025, 4, 30);
+
+        assert_eq!(parse_date("2025-04-27", today), Some(date(2025, 4, 27)));
+        assert_eq!(parse_date(" Today ", today), Some(today));
+        assert_eq!(parse_date("yesterday", today), Some(date(2025, 4, 29)));
+        assert_eq!(parse_date("-3d", today), Some(date(2025, 4, 27)));
+        assert_eq!(parse_date("+3", today), Some(date(2025, 5, 3)));
+        assert_eq!(parse_date("-2w", today), Some(date(2025, 4, 16)));
+        assert_eq!(parse_date("1m", today), Some(date(2025, 5, 30)));
+    }
+
+    #[test]
+    fn test_parse_weekdays() {
+        // a wednesday
+        let today = date(2025, 4, 30);
+
+        assert_eq!(parse_date("sat", today), Some(date(2025, 5, 3)));
+        assert_eq!(parse_date("next saturday", today), Some(date(2025, 5, 3)));
+        assert_eq!(parse_date("last sat", today), Some(date(2025, 4, 26)));
+        assert_eq!(parse_date("wed", today), Some(today));
+        assert_eq!(parse_date("next wed", today), Some(date(2025, 5, 7)));
+        assert_eq!(parse_date("last wed", today), Some(date(2025, 4, 23)));
+    }
+
+    #[test]
+    fn test_parse_invalid_dates() {
+        let today = date(2025, 4, 30);
+
+        for input in ["", "2025-02-30", "-3y", "soon", "next week", "d"] {
+            assert_eq!(parse_date(input, today), None, "{input}");
+        }
+    }
+}
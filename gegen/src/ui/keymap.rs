@@ -0,0 +1,744 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+
+use crate::state::Page;
+
+/// Everything a key can be bound to, the config file refers to these by their snake case name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Action {
+    Quit,
+    ToggleMetadata,
+    ToggleHelp,
+    ToggleMouse,
+    NextCompetition,
+    PreviousCompetition,
+    NextDay,
+    PreviousDay,
+    Today,
+    NextRow,
+    PreviousRow,
+    ScrollToTop,
+    ClearSelection,
+    OpenMatch,
+    Back,
+    FavouriteHomeTeam,
+    FavouriteAwayTeam,
+    FavouriteCompetition,
+    Search,
+    ToggleSidebar,
+    ToggleAllLive,
+    GoToDate,
+    Calendar,
+    WeekView,
+    NextWeek,
+    PreviousWeek,
+    ToggleRollingWindow,
+    CycleTimezone,
+    ToggleClockFormat,
+    OpenHomeTeam,
+    OpenAwayTeam,
+    LoadMoreDays,
+    OpenCompetition,
+    ToggleResults,
+    OpenStandings,
+    Confirm,
+    Close,
+    DeleteCharacter,
+    NextResult,
+    PreviousResult,
+    CalendarNextDay,
+    CalendarPreviousDay,
+    CalendarNextWeek,
+    CalendarPreviousWeek,
+    NextMonth,
+    PreviousMonth,
+}
+
+impl Action {
+    pub(crate) fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::ToggleMetadata => "show metadata",
+            Action::ToggleHelp => "show key binds",
+            Action::ToggleMouse => "toggle mouse capture",
+            Action::NextCompetition => "next competition",
+            Action::PreviousCompetition => "previous competition",
+            Action::NextDay => "next day",
+            Action::PreviousDay => "previous day",
+            Action::Today => "today",
+            Action::NextRow => "down",
+            Action::PreviousRow => "up",
+            Action::ScrollToTop => "back to top",
+            Action::ClearSelection => "clear selection",
+            Action::OpenMatch => "open match",
+            Action::Back => "back",
+            Action::FavouriteHomeTeam => "favourite home team",
+            Action::FavouriteAwayTeam => "favourite away team",
+            Action::FavouriteCompetition => "favourite competition",
+            Action::Search => "search",
+            Action::ToggleSidebar => "toggle sidebar",
+            Action::ToggleAllLive => "all live matches",
+            Action::GoToDate => "go to date",
+            Action::Calendar => "calendar",
+            Action::WeekView => "week view",
+            Action::NextWeek => "next week",
+            Action::PreviousWeek => "previous week",
+            Action::ToggleRollingWindow => "last and next 24 hours",
+            Action::CycleTimezone => "switch timezone",
+            Action::ToggleClockFormat => "12 or 24 hour clock",
+            Action::OpenHomeTeam => "home team page",
+            Action::OpenAwayTeam => "away team page",
+            Action::LoadMoreDays => "load another week either side",
+            Action::OpenCompetition => "competition page",
+            Action::ToggleResults => "results or fixtures",
+            Action::OpenStandings => "league table",
+            Action::Confirm => "confirm",
+            Action::Close => "close",
+            Action::DeleteCharacter => "delete a character",
+            Action::NextResult => "next result",
+            Action::PreviousResult => "previous result",
+            Action::CalendarNextDay => "next day",
+            Action::CalendarPreviousDay => "previous day",
+            Action::CalendarNextWeek => "next week",
+            Action::CalendarPreviousWeek => "previous week",
+            Action::NextMonth => "next month",
+            Action::PreviousMonth => "previous month",
+        }
+    }
+
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::ToggleMetadata => &["m"],
+            Action::ToggleHelp => &["?"],
+            Action::ToggleMouse => &["M"],
+            Action::NextCompetition => &["tab"],
+            Action::PreviousCompetition => &["shift-tab"],
+            Action::NextDay => &["n"],
+            Action::PreviousDay => &["p"],
+            Action::Today => &["t"],
+            Action::NextRow => &["j", "down"],
+            Action::PreviousRow => &["k", "up"],
+            Action::ScrollToTop => &["g"],
+            Action::ClearSelection => &["esc"],
+            Action::OpenMatch => &["enter"],
+            Action::Back => &["backspace"],
+            Action::FavouriteHomeTeam => &["f"],
+            Action::FavouriteAwayTeam => &["F"],
+            Action::FavouriteCompetition => &["c"],
+            Action::Search => &["/"],
+            Action::ToggleSidebar => &["b"],
+            Action::ToggleAllLive => &["a"],
+            Action::GoToDate => &["d"],
+            Action::Calendar => &["D"],
+            Action::WeekView => &["w"],
+            Action::NextWeek => &["n"],
+            Action::PreviousWeek => &["p"],
+            Action::ToggleRollingWindow => &["r"],
+            Action::CycleTimezone => &["z"],
+            Action::ToggleClockFormat => &["H"],
+            Action::OpenHomeTeam => &["o"],
+            Action::OpenAwayTeam => &["O"],
+            Action::LoadMoreDays => &["+"],
+            Action::OpenCompetition => &["C"],
+            Action::ToggleResults => &["r"],
+            Action::OpenStandings => &["s"],
+            Action::Confirm => &["enter"],
+            Action::Close => &["esc"],
+            Action::DeleteCharacter => &["backspace"],
+            Action::NextResult => &["down", "ctrl-n"],
+            Action::PreviousResult => &["up", "ctrl-p"],
+            Action::CalendarNextDay => &["l", "right"],
+            Action::CalendarPreviousDay => &["h", "left"],
+            Action::CalendarNextWeek => &["j", "down"],
+            Action::CalendarPreviousWeek => &["k", "up"],
+            Action::NextMonth => &["n", "pagedown"],
+            Action::PreviousMonth => &["p", "pageup"],
+        }
+    }
+}
+
+/// Where an action can be used, keys only conflict if their actions share a scope or one of them
+/// is global. Global actions aren't available in the pop ups so that their keys can be typed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyScope {
+    Global,
+    Matches,
+    MatchOverview,
+    Week,
+    Team,
+    Competition,
+    Standings,
+    Search,
+    DatePrompt,
+    Calendar,
+}
+
+impl KeyScope {
+    const PAGES: [KeyScope; 6] = [
+        KeyScope::Matches,
+        KeyScope::MatchOverview,
+        KeyScope::Week,
+        KeyScope::Team,
+        KeyScope::Competition,
+        KeyScope::Standings,
+    ];
+
+    const POP_UPS: [KeyScope; 3] = [KeyScope::Search, KeyScope::DatePrompt, KeyScope::Calendar];
+
+    pub(crate) fn for_page(page: &Page) -> Self {
+        match page {
+            Page::Matches(_) => KeyScope::Matches,
+            Page::MatchOverview { .. } => KeyScope::MatchOverview,
+            Page::Week { .. } => KeyScope::Week,
+            Page::Team { .. } => KeyScope::Team,
+            Page::Competition { .. } => KeyScope::Competition,
+            Page::Standings { .. } => KeyScope::Standings,
+        }
+    }
+
+    /// actions in the order they are listed in the help pop up
+    fn actions(self) -> &'static [Action] {
+        match self {
+            KeyScope::Global => &[
+                Action::Quit,
+                Action::ToggleMetadata,
+                Action::ToggleHelp,
+                Action::ToggleMouse,
+                Action::CycleTimezone,
+                Action::ToggleClockFormat,
+            ],
+            KeyScope::Matches => &[
+                Action::NextCompetition,
+                Action::PreviousCompetition,
+                Action::NextDay,
+                Action::PreviousDay,
+                Action::Today,
+                Action::NextRow,
+                Action::PreviousRow,
+                Action::ScrollToTop,
+                Action::ClearSelection,
+                Action::OpenMatch,
+                Action::FavouriteHomeTeam,
+                Action::FavouriteAwayTeam,
+                Action::FavouriteCompetition,
+                Action::Search,
+                Action::ToggleSidebar,
+                Action::ToggleAllLive,
+                Action::GoToDate,
+                Action::Calendar,
+                Action::WeekView,
+                Action::ToggleRollingWindow,
+                Action::OpenHomeTeam,
+                Action::OpenAwayTeam,
+                Action::OpenCompetition,
+                Action::OpenStandings,
+            ],
+            KeyScope::MatchOverview => &[
+                Action::Back,
+                Action::FavouriteHomeTeam,
+                Action::FavouriteAwayTeam,
+                Action::FavouriteCompetition,
+                Action::OpenHomeTeam,
+                Action::OpenAwayTeam,
+                Action::OpenCompetition,
+                Action::OpenStandings,
+            ],
+            KeyScope::Week => &[
+                Action::Back,
+                Action::NextWeek,
+                Action::PreviousWeek,
+                Action::NextRow,
+                Action::PreviousRow,
+            ],
+            KeyScope::Team => &[
+                Action::Back,
+                Action::NextRow,
+                Action::PreviousRow,
+                Action::OpenMatch,
+                Action::OpenHomeTeam,
+                Action::OpenAwayTeam,
+                Action::OpenCompetition,
+                Action::OpenStandings,
+                Action::LoadMoreDays,
+            ],
+            KeyScope::Competition => &[
+                Action::Back,
+                Action::NextRow,
+                Action::PreviousRow,
+                Action::OpenMatch,
+                Action::ToggleResults,
+                Action::OpenHomeTeam,
+                Action::OpenAwayTeam,
+                Action::OpenStandings,
+            ],
+            KeyScope::Standings => &[
+                Action::Back,
+                Action::NextRow,
+                Action::PreviousRow,
+                Action::LoadMoreDays,
+            ],
+            KeyScope::Search => &[
+                Action::Confirm,
+                Action::Close,
+                Action::NextResult,
+                Action::PreviousResult,
+                Action::DeleteCharacter,
+            ],
+            KeyScope::DatePrompt => &[Action::Confirm, Action::Close, Action::DeleteCharacter],
+            KeyScope::Calendar => &[
+                Action::Confirm,
+                Action::Close,
+                Action::CalendarNextDay,
+                Action::CalendarPreviousDay,
+                Action::CalendarNextWeek,
+                Action::CalendarPreviousWeek,
+                Action::NextMonth,
+                Action::PreviousMonth,
+                Action::Today,
+            ],
+        }
+    }
+
+    /// the pop ups that can be opened from this scope, their keys are listed in its help
+    pub(crate) fn pop_ups(self) -> &'static [KeyScope] {
+        match self {
+            KeyScope::Matches => &KeyScope::POP_UPS,
+            _ => &[],
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            KeyScope::Global => "all pages",
+            KeyScope::Matches => "the matches page",
+            KeyScope::MatchOverview => "the match overview page",
+            KeyScope::Week => "the week page",
+            KeyScope::Team => "the team page",
+            KeyScope::Competition => "the competition page",
+            KeyScope::Standings => "the standings page",
+            KeyScope::Search => "the search pop up",
+            KeyScope::DatePrompt => "the go to date prompt",
+            KeyScope::Calendar => "the calendar",
+        }
+    }
+
+    /// the actions available in this scope, including the global ones on pages
+    fn available_actions(self) -> impl Iterator<Item = Action> {
+        let global = if KeyScope::POP_UPS.contains(&self) {
+            [].as_slice()
+        } else {
+            KeyScope::Global.actions()
+        };
+        global.iter().chain(self.actions()).copied()
+    }
+}
+
+/// A key and the modifiers held with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+/// ctrl + c always quits, so it can't be bound to anything else
+const QUIT_BINDING: KeyBinding = KeyBinding {
+    code: KeyCode::Char('c'),
+    modifiers: KeyModifiers::CONTROL,
+};
+
+impl KeyBinding {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // shift is implied by the character itself, e.g. `G` or `?`, and by back tab
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers.difference(KeyModifiers::SHIFT),
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+}
+
+impl From<KeyEvent> for KeyBinding {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub(crate) enum KeymapError {
+    #[error("`{0}` is not a valid key")]
+    InvalidKey(String),
+    #[error("`{key}` is bound to both `{first:?}` and `{second:?}` on {scope}")]
+    Conflict {
+        key: KeyBinding,
+        first: Action,
+        second: Action,
+        scope: &'static str,
+    },
+    #[error("ctrl + c always quits and can't be bound to `{0:?}`")]
+    Reserved(Action),
+}
+
+impl FromStr for KeyBinding {
+    type Err = KeymapError;
+
+    /// Parses keys such as `q`, `G`, `enter`, `shift-tab` or `ctrl-n`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || KeymapError::InvalidKey(s.to_string());
+
+        // a single character is always the key itself, this allows `-` and `+` to be bound
+        let (modifier_names, key) = if s.chars().count() == 1 {
+            ("", s)
+        } else {
+            s.rsplit_once(['-', '+']).unwrap_or(("", s))
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in modifier_names.split(['-', '+']).filter(|m| !m.is_empty()) {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            // terminals report shift + g as `G`
+            (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "space" => KeyCode::Char(' '),
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                function_key => function_key
+                    .strip_prefix('f')
+                    .and_then(|n| n.parse().ok())
+                    .map(KeyCode::F)
+                    .ok_or_else(invalid)?,
+            },
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl Display for KeyBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl + ")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt + ")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "shift + ")?;
+        }
+
+        match self.code {
+            KeyCode::Enter => write!(f, "⏎"),
+            KeyCode::Tab => write!(f, "⇥"),
+            KeyCode::BackTab => write!(f, "shift + ⇥"),
+            KeyCode::Backspace => write!(f, "⌫"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            code => write!(f, "{}", code.to_string().to_lowercase()),
+        }
+    }
+}
+
+/// Keys from the `[keys]` section of the config file, either a single key or a list of keys
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ConfiguredKeys {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ConfiguredKeys {
+    fn keys(&self) -> &[String] {
+        match self {
+            ConfiguredKeys::One(key) => std::slice::from_ref(key),
+            ConfiguredKeys::Many(keys) => keys,
+        }
+    }
+}
+
+/// Maps keys to actions, the help pop up is generated from this so it always matches what the
+/// keys actually do
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Keymap {
+    bindings: HashMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&HashMap::new()).expect("default key binds are valid")
+    }
+}
+
+impl Keymap {
+    /// Builds the keymap from the defaults, an action configured in the config file replaces all
+    /// of its default keys
+    pub(crate) fn new(configured: &HashMap<Action, ConfiguredKeys>) -> Result<Self, KeymapError> {
+        let mut bindings = HashMap::new();
+
+        let all_actions = KeyScope::Global.actions().iter().chain(
+            KeyScope::PAGES
+                .iter()
+                .chain(&KeyScope::POP_UPS)
+                .flat_map(|scope| scope.actions()),
+        );
+
+        for &action in all_actions {
+            let keys = match configured.get(&action) {
+                Some(configured_keys) => configured_keys
+                    .keys()
+                    .iter()
+                    .map(|key| key.parse())
+                    .collect::<Result<Vec<KeyBinding>, _>>()?,
+                None => action
+                    .default_keys()
+                    .iter()
+                    .map(|key| key.parse())
+                    .collect::<Result<Vec<KeyBinding>, _>>()?,
+            };
+
+            if keys.contains(&QUIT_BINDING) && action != Action::Quit {
+                return Err(KeymapError::Reserved(action));
+            }
+
+            bindings.insert(action, keys);
+        }
+
+        let keymap = Self { bindings };
+        keymap.check_for_conflicts()?;
+        Ok(keymap)
+    }
+
+    fn check_for_conflicts(&self) -> Result<(), KeymapError> {
+        for scope in KeyScope::PAGES.into_iter().chain(KeyScope::POP_UPS) {
+            let mut seen: HashMap<KeyBinding, Action> = HashMap::new();
+            for action in scope.available_actions() {
+                for &key in self.keys_for(action) {
+                    if let Some(&first) = seen.get(&key)
+                        && first != action
+                    {
+                        let scope = if KeyScope::Global.actions().contains(&action) {
+                            KeyScope::Global
+                        } else {
+                            scope
+                        };
+                        return Err(KeymapError::Conflict {
+                            key,
+                            first,
+                            second: action,
+                            scope: scope.name(),
+                        });
+                    }
+                    seen.insert(key, action);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn keys_for(&self, action: Action) -> &[KeyBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// the action bound to `key` on the current page or pop up, ctrl + c is always
+    /// [`Action::Quit`]
+    pub(crate) fn action(&self, scope: KeyScope, key: KeyEvent) -> Option<Action> {
+        let key = KeyBinding::from(key);
+        if key == QUIT_BINDING {
+            return Some(Action::Quit);
+        }
+
+        scope
+            .available_actions()
+            .find(|&action| self.keys_for(action).contains(&key))
+    }
+
+    /// lines for the help pop up, e.g. `j / ↓ - down`
+    pub(crate) fn help(&self, scope: KeyScope) -> Vec<String> {
+        scope
+            .available_actions()
+            .filter(|&action| !self.keys_for(action).is_empty())
+            .map(|action| {
+                let keys = self
+                    .keys_for(action)
+                    .iter()
+                    .map(KeyBinding::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" / ");
+                format!("{keys} - {}", action.description())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            "ctrl-n".parse(),
+            Ok(KeyBinding::new(KeyCode::Char('n'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            "shift-tab".parse(),
+            Ok(KeyBinding::new(KeyCode::BackTab, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            "-".parse(),
+            Ok(KeyBinding::new(KeyCode::Char('-'), KeyModifiers::NONE))
+        );
+        assert_eq!(
+            "F5".parse(),
+            Ok(KeyBinding::new(KeyCode::F(5), KeyModifiers::NONE))
+        );
+        assert!("hyper-x".parse::<KeyBinding>().is_err());
+        assert!("nope".parse::<KeyBinding>().is_err());
+    }
+
+    #[test]
+    fn test_default_bindings() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.action(
+                KeyScope::Matches,
+                key(KeyCode::Char('n'), KeyModifiers::NONE)
+            ),
+            Some(Action::NextDay)
+        );
+        assert_eq!(
+            keymap.action(
+                KeyScope::MatchOverview,
+                key(KeyCode::Char('n'), KeyModifiers::NONE)
+            ),
+            None
+        );
+        assert_eq!(
+            keymap.action(
+                KeyScope::MatchOverview,
+                key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+            ),
+            Some(Action::Quit)
+        );
+        // crossterm reports back tab with shift held
+        assert_eq!(
+            keymap.action(
+                KeyScope::Matches,
+                key(KeyCode::BackTab, KeyModifiers::SHIFT)
+            ),
+            Some(Action::PreviousCompetition)
+        );
+    }
+
+    #[test]
+    fn test_pop_up_bindings() {
+        let keymap = Keymap::default();
+        // global keys can be typed into the search
+        assert_eq!(
+            keymap.action(
+                KeyScope::Search,
+                key(KeyCode::Char('q'), KeyModifiers::NONE)
+            ),
+            None
+        );
+        assert_eq!(
+            keymap.action(
+                KeyScope::Search,
+                key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+            ),
+            Some(Action::NextResult)
+        );
+        assert_eq!(
+            keymap.action(
+                KeyScope::Calendar,
+                key(KeyCode::Char('n'), KeyModifiers::NONE)
+            ),
+            Some(Action::NextMonth)
+        );
+        assert_eq!(
+            keymap.action(KeyScope::DatePrompt, key(KeyCode::Esc, KeyModifiers::NONE)),
+            Some(Action::Close)
+        );
+    }
+
+    #[test]
+    fn test_configured_keys_replace_defaults() {
+        let configured = HashMap::from([(Action::NextDay, ConfiguredKeys::One("l".into()))]);
+        let keymap = Keymap::new(&configured).unwrap();
+        assert_eq!(
+            keymap.action(
+                KeyScope::Matches,
+                key(KeyCode::Char('l'), KeyModifiers::NONE)
+            ),
+            Some(Action::NextDay)
+        );
+        assert_eq!(
+            keymap.action(
+                KeyScope::Matches,
+                key(KeyCode::Char('n'), KeyModifiers::NONE)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let configured = HashMap::from([(Action::NextDay, ConfiguredKeys::One("q".into()))]);
+        assert!(matches!(
+            Keymap::new(&configured),
+            Err(KeymapError::Conflict {
+                first: Action::Quit,
+                second: Action::NextDay,
+                ..
+            })
+        ));
+
+        // the same key can be used on different pages
+        let configured = HashMap::from([(Action::Back, ConfiguredKeys::One("a".into()))]);
+        assert!(Keymap::new(&configured).is_ok());
+
+        let configured = HashMap::from([(Action::Back, ConfiguredKeys::One("ctrl-c".into()))]);
+        assert_eq!(
+            Keymap::new(&configured),
+            Err(KeymapError::Reserved(Action::Back))
+        );
+    }
+}
@@ -1,114 +1,268 @@
-use crossterm::event::{Event, KeyCode, KeyModifiers};
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, Event, MouseButton, MouseEvent, MouseEventKind,
+};
+use gegen_data::types::Side;
 use ratatui::{
     Frame,
-    layout::{Constraint, Flex, Layout, Rect},
+    layout::{Constraint, Flex, Layout, Position, Rect},
     style::{Style, Stylize},
     text::Line,
-    widgets::{Block, Clear, Paragraph},
+    widgets::{Block, Clear, Paragraph, Wrap},
 };
 
+pub(crate) mod date_picker;
+pub(crate) mod keymap;
 mod pages;
+pub(crate) mod search;
+pub(crate) mod theme;
 use crate::{
     GEGEN_VERSION, PageRenderStates,
-    state::{Page, State},
+    state::{LiveScoresPageState, Page, State},
 };
+use date_picker::{CalendarState, DatePromptState};
+use keymap::{Action, KeyScope};
+use search::SearchState;
 
 pub(crate) fn process_event(
     event: Event,
     app_state: &mut State,
     page_states: &mut PageRenderStates,
 ) {
-    if let Event::Key(key) = event {
-        if key.modifiers == KeyModifiers::CONTROL && key.code == KeyCode::Char('c') {
-            app_state.should_quit = true;
+    // the config error pop up swallows the first key press or click so it isn't missed
+    if app_state.config_error.is_some()
+        && matches!(
+            event,
+            Event::Key(_)
+                | Event::Mouse(MouseEvent {
+                    kind: MouseEventKind::Down(_),
+                    ..
+                })
+        )
+    {
+        app_state.dismiss_config_error();
+        return;
+    }
+
+    // the pop ups only take keys, clicks and scrolls mustn't reach the page underneath
+    if page_states.search.is_some() {
+        if let Event::Key(key) = event {
+            search::process_key(key, app_state, page_states);
+        }
+        return;
+    }
+
+    if page_states.date_prompt.is_some() {
+        if let Event::Key(key) = event {
+            date_picker::process_prompt_key(key, app_state, page_states);
+        }
+        return;
+    }
+
+    if page_states.calendar.is_some() {
+        if let Event::Key(key) = event {
+            date_picker::process_calendar_key(key, app_state, page_states);
+        }
+        return;
+    }
+
+    match event {
+        Event::Key(key) => {
+            let scope = KeyScope::for_page(&app_state.current_page);
+            let Some(action) = app_state.keymap.action(scope, key) else {
+                return;
+            };
+
+            handle_action(action, app_state, page_states);
         }
-        match key.code {
-            KeyCode::Char('q') => app_state.should_quit = true,
-            KeyCode::Char('m') => app_state.toggle_metadata_pop_up(),
-            KeyCode::Char('?') => app_state.toggle_key_bind_pop_up(),
-            _ => (),
+        Event::Mouse(mouse) => handle_mouse(mouse, app_state, page_states),
+        _ => (),
+    }
+}
+
+fn handle_mouse(mouse: MouseEvent, app_state: &mut State, page_states: &mut PageRenderStates) {
+    let position = Position::new(mouse.column, mouse.row);
+
+    match (&app_state.current_page, mouse.kind) {
+        (Page::Matches(_), MouseEventKind::ScrollDown)
+            if page_states.live_scores.sidebar_area.contains(position) =>
+        {
+            app_state.next_competition(page_states)
         }
+        (Page::Matches(_), MouseEventKind::ScrollUp)
+            if page_states.live_scores.sidebar_area.contains(position) =>
+        {
+            app_state.previous_competition(page_states)
+        }
+        (Page::Matches(_), MouseEventKind::ScrollDown) => app_state.next_row(page_states),
+        (Page::Matches(_), MouseEventKind::ScrollUp) => app_state.previous_row(page_states),
+        (Page::Matches(_), MouseEventKind::Down(MouseButton::Left)) => {
+            click_matches_page(position, app_state, page_states)
+        }
+        (Page::MatchOverview { .. }, MouseEventKind::ScrollDown)
+            if page_states.match_overview.events_area.contains(position) =>
+        {
+            let offset = page_states.match_overview.events_table_state.offset_mut();
+            *offset = offset.saturating_add(1);
+        }
+        (Page::MatchOverview { .. }, MouseEventKind::ScrollUp)
+            if page_states.match_overview.events_area.contains(position) =>
+        {
+            let offset = page_states.match_overview.events_table_state.offset_mut();
+            *offset = offset.saturating_sub(1);
+        }
+        (Page::Week { .. }, MouseEventKind::ScrollDown) => app_state.next_row(page_states),
+        (Page::Week { .. }, MouseEventKind::ScrollUp) => app_state.previous_row(page_states),
+        (Page::Team { .. }, MouseEventKind::ScrollDown) => app_state.next_row(page_states),
+        (Page::Team { .. }, MouseEventKind::ScrollUp) => app_state.previous_row(page_states),
+        (Page::Competition { .. }, MouseEventKind::ScrollDown) => app_state.next_row(page_states),
+        (Page::Competition { .. }, MouseEventKind::ScrollUp) => app_state.previous_row(page_states),
+        (Page::Standings { .. }, MouseEventKind::ScrollDown) => app_state.next_row(page_states),
+        (Page::Standings { .. }, MouseEventKind::ScrollUp) => app_state.previous_row(page_states),
+        _ => (),
     }
+}
 
-    match app_state.current_page {
-        Page::Matches(_) => {
-            if let Event::Key(key) = event {
-                match key.code {
-                    KeyCode::Esc => page_states.live_scores.table_state.select(None),
-                    KeyCode::Char('n') => app_state.next_day(page_states),
-                    KeyCode::Char('p') => app_state.previous_day(page_states),
-                    KeyCode::Char('t') => app_state.reset_to_today(),
-                    KeyCode::Char('g') => page_states.live_scores.reset_scroll_state(),
-                    KeyCode::Enter => {
-                        let selected_tab = app_state.selected_tab(page_states);
-                        let Some(selected_row) = app_state.selected_row(page_states) else {
-                            return;
-                        };
-
-                        let Page::Matches(date) = app_state.current_page else {
-                            return;
-                        };
-
-                        let Some(grouped_data) = app_state.get_grouped_data() else {
-                            return;
-                        };
-
-                        let Some(matches_in_tab) = grouped_data.get(selected_tab) else {
-                            return;
-                        };
-
-                        let Some(selected_match) = matches_in_tab.1.get(selected_row) else {
-                            return;
-                        };
-
-                        let competition_name = matches_in_tab.0.clone();
-
-                        app_state.current_page = Page::MatchOverview {
-                            date,
-                            match_id: selected_match.id.clone(),
-                            competition_name,
-                        };
-                    }
-                    KeyCode::Char('k') | KeyCode::Up => {
-                        app_state.previous_row(page_states);
-                    }
-                    KeyCode::Char('j') | KeyCode::Down => {
-                        app_state.next_row(page_states);
-                    }
-                    KeyCode::Tab => {
-                        let Some(grouped_data) = app_state.get_grouped_data() else {
-                            return;
-                        };
-                        // subtract one here because tabs are zero indexed
-                        let max_no_tabs = grouped_data.len() - 1;
-
-                        page_states.live_scores.selected_tab = page_states
-                            .live_scores
-                            .selected_tab
-                            .saturating_add(1)
-                            .min(max_no_tabs);
-                        page_states.live_scores.table_state = Default::default();
-                    }
-                    KeyCode::BackTab => {
-                        page_states.live_scores.selected_tab =
-                            page_states.live_scores.selected_tab.saturating_sub(1);
-
-                        page_states.live_scores.table_state = Default::default();
-                    }
-                    _ => (),
-                }
-            }
+fn click_matches_page(
+    position: Position,
+    app_state: &mut State,
+    page_states: &mut PageRenderStates,
+) {
+    let live_scores = &page_states.live_scores;
+
+    if live_scores.date_nav.previous_day.contains(position) {
+        app_state.previous_day(page_states);
+    } else if live_scores.date_nav.next_day.contains(position) {
+        app_state.next_day(page_states);
+    } else if let Some(tab) = live_scores
+        .tab_areas
+        .iter()
+        .position(|tab_area| tab_area.contains(position))
+        .or_else(|| sidebar_tab_at(live_scores, position))
+    {
+        page_states.live_scores.selected_tab = tab;
+        page_states.live_scores.table_state = Default::default();
+    } else if live_scores.table_area.contains(position) {
+        let row =
+            live_scores.table_state.offset() + usize::from(position.y - live_scores.table_area.y);
+
+        // rows past the last match, or competition headers in the all live view, don't open
+        // anything so the selection is put back
+        let previous_selection = live_scores.table_state.selected();
+        page_states.live_scores.table_state.select(Some(row));
+        if app_state.current_match(page_states).is_some() {
+            open_selected_match(app_state, page_states);
+        } else {
+            page_states
+                .live_scores
+                .table_state
+                .select(previous_selection);
         }
-        Page::MatchOverview { date, .. } => {
-            if let Event::Key(key) = event
-                && key.code == KeyCode::Backspace
-            {
-                app_state.current_page = Page::Matches(date);
-            }
+    }
+}
+
+/// the competition clicked in the sidebar, if any
+fn sidebar_tab_at(live_scores: &LiveScoresPageState, position: Position) -> Option<usize> {
+    if !live_scores.sidebar_area.contains(position) {
+        return None;
+    }
+    let item =
+        live_scores.sidebar_state.offset() + usize::from(position.y - live_scores.sidebar_area.y);
+    live_scores.sidebar_tabs.get(item).copied().flatten()
+}
+
+/// mouse capture stops the terminal from selecting text, so it can be turned off
+pub(crate) fn set_mouse_capture(enabled: bool) -> std::io::Result<()> {
+    if enabled {
+        crossterm::execute!(std::io::stdout(), EnableMouseCapture)
+    } else {
+        crossterm::execute!(std::io::stdout(), DisableMouseCapture)
+    }
+}
+
+fn handle_action(action: Action, app_state: &mut State, page_states: &mut PageRenderStates) {
+    match action {
+        Action::Quit => app_state.should_quit = true,
+        Action::ToggleMetadata => app_state.toggle_metadata_pop_up(),
+        Action::ToggleHelp => app_state.toggle_key_bind_pop_up(),
+        Action::ToggleMouse => app_state.toggle_mouse_capture(),
+        Action::CycleTimezone => app_state.cycle_timezone(),
+        Action::ToggleClockFormat => app_state.toggle_clock_format(),
+        Action::ClearSelection => page_states.live_scores.table_state.select(None),
+        Action::NextDay => app_state.next_day(page_states),
+        Action::PreviousDay => app_state.previous_day(page_states),
+        Action::Today => app_state.reset_to_today(),
+        Action::ScrollToTop => page_states.live_scores.reset_scroll_state(),
+        Action::OpenMatch => open_selected_match(app_state, page_states),
+        Action::PreviousRow => app_state.previous_row(page_states),
+        Action::NextRow => app_state.next_row(page_states),
+        Action::NextCompetition => app_state.next_competition(page_states),
+        Action::PreviousCompetition => app_state.previous_competition(page_states),
+        Action::ToggleSidebar => app_state.toggle_sidebar(),
+        Action::ToggleAllLive => page_states.live_scores.toggle_all_live(),
+        Action::FavouriteHomeTeam => app_state.toggle_favourite_team(Side::Home, page_states),
+        Action::FavouriteAwayTeam => app_state.toggle_favourite_team(Side::Away, page_states),
+        Action::FavouriteCompetition => app_state.toggle_favourite_competition(page_states),
+        Action::Search => page_states.search = Some(SearchState::default()),
+        Action::GoToDate => page_states.date_prompt = Some(DatePromptState::default()),
+        Action::Calendar => {
+            page_states.calendar = Some(CalendarState {
+                selected: app_state.current_date(),
+            })
         }
+        Action::WeekView => app_state.open_week(page_states),
+        Action::ToggleRollingWindow => app_state.toggle_rolling_window(page_states),
+        Action::NextWeek => app_state.next_week(page_states),
+        Action::PreviousWeek => app_state.previous_week(page_states),
+        Action::OpenHomeTeam => app_state.open_team_page(Side::Home, page_states),
+        Action::OpenAwayTeam => app_state.open_team_page(Side::Away, page_states),
+        Action::LoadMoreDays => app_state.load_more_days(page_states),
+        Action::OpenCompetition => app_state.open_competition_page(page_states),
+        Action::ToggleResults => page_states.competition.toggle_results(),
+        Action::OpenStandings => app_state.open_standings_page(page_states),
+        Action::Back => app_state.go_back(page_states),
+        // pop up actions are handled by the pop up that is open
+        Action::Confirm
+        | Action::Close
+        | Action::DeleteCharacter
+        | Action::NextResult
+        | Action::PreviousResult
+        | Action::CalendarNextDay
+        | Action::CalendarPreviousDay
+        | Action::CalendarNextWeek
+        | Action::CalendarPreviousWeek
+        | Action::NextMonth
+        | Action::PreviousMonth => (),
     }
 }
 
+fn open_selected_match(app_state: &mut State, page_states: &mut PageRenderStates) {
+    let Some(selected_match) = app_state.current_match(page_states) else {
+        return;
+    };
+    let date = match app_state.current_page {
+        Page::Matches(date) => date,
+        // matches on the team and competition pages can be on any day
+        Page::Team { .. } | Page::Competition { .. } => {
+            app_state.clock.date_of(selected_match.date)
+        }
+        Page::MatchOverview { .. } | Page::Week { .. } | Page::Standings { .. } => return,
+    };
+
+    let competition_name = format!(
+        "{} - {}",
+        selected_match.comp.country.full_name, selected_match.comp.name
+    );
+
+    app_state.open_page(
+        Page::MatchOverview {
+            date,
+            match_id: selected_match.id,
+            competition_name,
+        },
+        page_states,
+    );
+}
+
 pub(crate) fn draw_page(frame: &mut Frame, app_state: &State, render_state: &mut PageRenderStates) {
     match &app_state.current_page {
         Page::Matches(date) => {
@@ -127,21 +281,81 @@ pub(crate) fn draw_page(frame: &mut Frame, app_state: &State, render_state: &mut
             match_id,
             competition_name,
         ),
+        Page::Week {
+            start,
+            competition_name,
+        } => pages::week::draw(frame, app_state, render_state, start, competition_name),
+        Page::Team { team_id, team_name } => {
+            pages::team::draw(frame, app_state, render_state, team_id, team_name)
+        }
+        Page::Competition {
+            competition_id,
+            competition_name,
+        } => pages::competition::draw(
+            frame,
+            app_state,
+            render_state,
+            competition_id,
+            competition_name,
+        ),
+        Page::Standings {
+            competition_id,
+            competition_name,
+            highlighted,
+        } => pages::standings::draw(
+            frame,
+            app_state,
+            render_state,
+            competition_id,
+            competition_name,
+            highlighted,
+        ),
     }
 
     if app_state.show_metadata_pop_up {
-        draw_metadata_pop_up(frame);
+        draw_metadata_pop_up(frame, app_state);
     }
 
     if app_state.show_key_bind_pop_up {
         draw_key_bind_pop_up(frame, app_state)
     }
+
+    if let Some(search_state) = &mut render_state.search {
+        search::draw_search_pop_up(frame, app_state, search_state);
+    }
+
+    if let Some(prompt_state) = &render_state.date_prompt {
+        date_picker::draw_date_prompt(frame, app_state, prompt_state);
+    }
+
+    if let Some(calendar_state) = &render_state.calendar {
+        date_picker::draw_calendar(frame, app_state, calendar_state);
+    }
+
+    if let Some(config_error) = &app_state.config_error {
+        draw_config_error_pop_up(frame, app_state, config_error);
+    }
 }
 
-fn draw_metadata_pop_up(frame: &mut Frame) {
+fn draw_config_error_pop_up(frame: &mut Frame, app_state: &State, config_error: &str) {
+    let block = Block::bordered()
+        .title("Config error")
+        .title_style(Style::new().fg(app_state.theme.popup_title));
+    let paragraph = Paragraph::new(vec![
+        Line::raw(config_error.to_string()),
+        Line::raw(""),
+        Line::raw("press any key to dismiss").italic(),
+    ])
+    .wrap(Wrap { trim: false });
+    let area = popup_area(frame.area(), 60, 30);
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph.block(block), area);
+}
+
+fn draw_metadata_pop_up(frame: &mut Frame, app_state: &State) {
     let block = Block::bordered()
         .title("Metadata")
-        .title_style(Style::new().red());
+        .title_style(Style::new().fg(app_state.theme.popup_title));
     let paragraph = Paragraph::new(vec![
         Line::raw(format!("version: {GEGEN_VERSION}")),
         Line::raw("github: https://github.com/benjaminjellis/gegen"),
@@ -154,28 +368,49 @@ fn draw_metadata_pop_up(frame: &mut Frame) {
 fn draw_key_bind_pop_up(frame: &mut Frame, app_state: &State) {
     let block = Block::bordered()
         .title("Key binds")
-        .title_style(Style::new().red());
-
-    let paragraph = match app_state.current_page {
-        Page::Matches(_) => Paragraph::new(vec![
-            Line::raw("q - quit"),
-            Line::raw("⇥ - next competition"),
-            Line::raw("shift + ⇥ - previous competition"),
-            Line::raw("n - next day"),
-            Line::raw("p - previous day"),
-            Line::raw("t - today"),
-            Line::raw("j / ↑ - down"),
-            Line::raw("k / ↓ - up"),
-            Line::raw("⏎ - up"),
-        ]),
-        Page::MatchOverview { .. } => {
-            Paragraph::new(vec![Line::raw("q - quit"), Line::raw("⌫ - back")])
-        }
-    };
+        .title_style(Style::new().fg(app_state.theme.popup_title));
 
-    let area = popup_area(frame.area(), 60, 50);
+    let scope = KeyScope::for_page(&app_state.current_page);
+    let mut help = app_state.keymap.help(scope);
+    for &pop_up in scope.pop_ups() {
+        help.push(String::new());
+        help.push(format!("on {}:", pop_up.name()));
+        help.extend(app_state.keymap.help(pop_up));
+    }
+
+    // the help is split into as many columns as it takes to fit it on the screen
+    let available_rows = usize::from(frame.area().height.saturating_sub(4)).max(1);
+    let column_count = help.len().div_ceil(available_rows).max(1);
+    let rows = help.len().div_ceil(column_count).max(1);
+    let columns = help.chunks(rows).collect::<Vec<_>>();
+    let column_width = help
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2;
+
+    let to_u16 = |value: usize| u16::try_from(value).unwrap_or(u16::MAX);
+    let area = centered_area(
+        frame.area(),
+        to_u16(column_width * columns.len() + 2),
+        to_u16(rows + 2),
+    );
     frame.render_widget(Clear, area);
-    frame.render_widget(paragraph.block(block), area);
+
+    let column_areas = Layout::horizontal(
+        columns
+            .iter()
+            .map(|_| Constraint::Length(to_u16(column_width))),
+    )
+    .split(block.inner(area));
+    frame.render_widget(block, area);
+    for (column, column_area) in columns.into_iter().zip(column_areas.iter()) {
+        frame.render_widget(
+            Paragraph::new(column.iter().map(Line::raw).collect::<Vec<_>>()),
+            *column_area,
+        );
+    }
 }
 
 /// helper function to create a centered rect using up certain percentage of the available rect `r`
@@ -186,3 +421,12 @@ fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let [area] = horizontal.areas(area);
     area
 }
+
+/// a centered rect of a fixed size, shrunk to fit if `area` is too small
+fn centered_area(area: Rect, width: u16, height: u16) -> Rect {
+    let vertical = Layout::vertical([Constraint::Length(height)]).flex(Flex::Center);
+    let horizontal = Layout::horizontal([Constraint::Length(width)]).flex(Flex::Center);
+    let [area] = vertical.areas(area);
+    let [area] = horizontal.areas(area);
+    area
+}
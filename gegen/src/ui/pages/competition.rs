@@ -0,0 +1,133 @@
+use chrono::NaiveDate;
+use gegen_data::types::CompetitionId;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    symbols,
+    text::Line,
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::{
+    PageRenderStates, State,
+    state::{CompetitionRow, CompetitionView},
+};
+
+use super::{
+    live_scores::{FIXTURE_COLUMNS, build_row},
+    shared::{check_for_anomalies, render_loading},
+};
+
+pub(crate) fn draw(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    competition_id: &CompetitionId,
+    competition_name: &str,
+) {
+    let theme = &app_state.theme;
+    let (first, last) = app_state.competition_window();
+
+    let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
+    let [header_area, content_area] = vertical.areas(frame.area());
+
+    let view = render_state.competition.view;
+    let view_name = match view {
+        CompetitionView::Fixtures => "Fixtures",
+        CompetitionView::Results => "Results",
+    };
+
+    let [title_area, window_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(header_area);
+    frame.render_widget(
+        Paragraph::new(format!(" {competition_name}"))
+            .fg(theme.title)
+            .bold(),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!(
+            "{view_name} · {} - {} ",
+            first.format("%d %b"),
+            last.format("%d %b")
+        ))
+        .alignment(Alignment::Right)
+        .fg(theme.accent)
+        .bold(),
+        window_area,
+    );
+
+    if !app_state.data.contains_key(&app_state.today) {
+        render_loading(
+            frame,
+            theme,
+            content_area,
+            &mut render_state.live_scores.throbber_state,
+        );
+        return;
+    }
+
+    let mut block = Block::bordered()
+        .border_set(symbols::border::DOUBLE)
+        .border_style(theme.border)
+        .title(Line::from(format!(" {view_name} ")).fg(theme.title).bold());
+    if !app_state.is_range_loaded(first, last) {
+        block = block.title_bottom(
+            Line::from(" loading more days... ")
+                .right_aligned()
+                .italic(),
+        );
+    }
+
+    let competition_rows = app_state.competition_rows(competition_id, view);
+    if competition_rows.is_empty() {
+        frame.render_widget(
+            Paragraph::new(format!("No {} found", view_name.to_lowercase()))
+                .italic()
+                .alignment(Alignment::Center)
+                .block(block),
+            content_area,
+        );
+        return;
+    }
+
+    // alternate row colours restart for each day, as they do in the all live view
+    let mut idx = 0;
+    let rows = competition_rows
+        .iter()
+        .map(|row| match row {
+            CompetitionRow::Day(date) => {
+                idx = 0;
+                Row::new(vec![
+                    Cell::new(""),
+                    Cell::new(""),
+                    Cell::new(day_title(*date, app_state.today)),
+                ])
+                .style(Style::new().fg(theme.accent).bold())
+            }
+            CompetitionRow::Match(match_data) => {
+                check_for_anomalies(match_data, &mut render_state.anomalies);
+                let row = build_row(app_state, idx, match_data);
+                idx += 1;
+                row
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let table = Table::new(rows, FIXTURE_COLUMNS).row_highlight_style(theme.selected_row_style());
+    frame.render_stateful_widget(
+        table.block(block),
+        content_area,
+        &mut render_state.competition.table_state,
+    );
+}
+
+fn day_title(date: NaiveDate, today: NaiveDate) -> String {
+    if date == today {
+        format!("Today {}", date.format("%a %d %b"))
+    } else {
+        date.format("%a %d %b").to_string()
+    }
+}
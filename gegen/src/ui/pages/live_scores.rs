@@ -1,17 +1,36 @@
-use chrono::{Local, NaiveDate};
-use gegen_data::types::{Match, ScoreKey, Team};
+use chrono::NaiveDate;
+use gegen_data::types::{Match, Period, Status, Team};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style, Stylize},
     symbols,
+    text::Span,
     text::{Line, Text},
-    widgets::{Block, Cell, Row, Table, Tabs, Widget},
+    widgets::{Block, Cell, List, ListItem, Paragraph, Row, Table, Tabs, Widget},
 };
 
-use crate::{PageRenderStates, State};
+use crate::{
+    PageRenderStates, State,
+    favourites::Favourites,
+    state::{AllLiveRow, FAVOURITES_TAB, MatchesView},
+};
+
+/// wide enough for most competition names along with the match count
+const SIDEBAR_WIDTH: u16 = 32;
+
+/// the widths of the columns made by [`build_row`]
+pub(in crate::ui::pages) const FIXTURE_COLUMNS: [Constraint; 5] = [
+    Constraint::Min(0),
+    Constraint::Min(10),
+    Constraint::Percentage(50),
+    Constraint::Min(25),
+    Constraint::Percentage(50),
+];
 
-use super::shared::{render_loading, render_title};
+use super::shared::{
+    check_for_anomalies, format_score, render_loading, render_title, status_label,
+};
 
 fn calculate_loading_layout(area: Rect) -> [Rect; 2] {
     let main_layout = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
@@ -24,40 +43,60 @@ pub(crate) fn draw(
     render_state: &mut PageRenderStates,
     date: &NaiveDate,
 ) {
+    app_state.fetch_data_for_matches_page(*date);
+
+    if render_state.live_scores.view == MatchesView::AllLive
+        && let Some(rows) = app_state.all_live_rows()
+    {
+        draw_all_live(frame, app_state, render_state, date, &rows);
+        return;
+    }
+
     match app_state.get_grouped_data() {
         Some(data_grouped) => {
             let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
             let [header_area, inner_area] = vertical.areas(frame.area());
 
-            let horizontal = Layout::vertical([Constraint::Min(1), Constraint::Percentage(100)]);
-            let [tabs_area, content_area] = horizontal.areas(inner_area);
-
             let selected_tab = render_state.live_scores.selected_tab;
 
             let tab_title = match data_grouped.get(selected_tab) {
                 Some((key, _)) => key.clone(),
                 None => "".into(),
             };
+            let tab_title = if app_state.rolling_window {
+                format!("{tab_title} · last and next 24 hours")
+            } else {
+                tab_title
+            };
 
-            let titles = data_grouped.iter().map(|(key, _)| {
-                let mut tab_title = key.to_string();
-                tab_title.truncate(5);
-                Line::from(tab_title)
-            });
-
-            render_title(frame, header_area, date, &app_state.today, tab_title);
+            render_state.live_scores.date_nav = render_title(
+                frame,
+                app_state,
+                header_area,
+                date,
+                tab_title,
+                !app_state.rolling_window,
+            )
+            .unwrap_or_default();
 
-            // tabs
-            let highlight_style = Style::new().bg(Color::Green).fg(Color::Magenta).bold();
-            Tabs::new(titles)
-                .highlight_style(highlight_style)
-                .select(Some(selected_tab))
-                .padding("", "")
-                .render(tabs_area, frame.buffer_mut());
+            let content_area = if app_state.show_sidebar {
+                let horizontal =
+                    Layout::horizontal([Constraint::Length(SIDEBAR_WIDTH), Constraint::Min(0)]);
+                let [sidebar_area, content_area] = horizontal.areas(inner_area);
+                draw_sidebar(frame, app_state, render_state, &data_grouped, sidebar_area);
+                render_state.live_scores.tab_areas.clear();
+                content_area
+            } else {
+                let vertical = Layout::vertical([Constraint::Min(1), Constraint::Percentage(100)]);
+                let [tabs_area, content_area] = vertical.areas(inner_area);
+                draw_tabs(frame, app_state, render_state, &data_grouped, tabs_area);
+                render_state.live_scores.sidebar_area = Rect::default();
+                content_area
+            };
 
             let block = Block::bordered()
                 .border_set(symbols::border::DOUBLE)
-                .border_style(Color::Green);
+                .border_style(app_state.theme.border);
 
             let Some(fixtures) = data_grouped.get(selected_tab) else {
                 return;
@@ -67,159 +106,279 @@ pub(crate) fn draw(
                 .1
                 .iter()
                 .enumerate()
-                .map(|(idx, fixture)| build_row(idx, fixture))
+                .map(|(idx, fixture)| {
+                    check_for_anomalies(fixture, &mut render_state.anomalies);
+                    build_row(app_state, idx, fixture)
+                })
                 .collect::<Vec<_>>();
 
-            let selected_row_style = Style::default().bg(Color::White).fg(Color::LightMagenta);
-
-            let table = Table::new(
-                rows,
-                [
-                    Constraint::Min(0),
-                    Constraint::Min(10),
-                    Constraint::Percentage(50),
-                    Constraint::Min(20),
-                    Constraint::Percentage(50),
-                ],
-            )
-            .row_highlight_style(selected_row_style)
-            .block(block);
-
-            frame.render_stateful_widget(
-                table,
-                content_area,
-                &mut render_state.live_scores.table_state,
-            );
+            render_fixtures_table(frame, app_state, render_state, rows, block, content_area);
         }
         None => {
-            app_state.fetch_data_for_date(*date);
             let [title_area, layout] = calculate_loading_layout(frame.area());
 
-            render_title(frame, title_area, date, &app_state.today, "".to_string());
-            render_loading(frame, layout, &mut render_state.live_scores.throbber_state);
+            render_title(frame, app_state, title_area, date, "".to_string(), true);
+            render_loading(
+                frame,
+                &app_state.theme,
+                layout,
+                &mut render_state.live_scores.throbber_state,
+            );
         }
     }
 }
 
-fn build_row<'a>(idx: usize, fixture: &'a Match) -> Row<'a> {
-    let (row_color, text_color) = match idx % 2 {
-        0 => (Color::Black, Color::White),
-        _ => (Color::Green, Color::Black),
-    };
+/// Every match in play on the date, grouped under a row with the competition name. There's no
+/// sidebar or tabs as every competition is shown at once
+fn draw_all_live(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    date: &NaiveDate,
+    all_live_rows: &[AllLiveRow],
+) {
+    let theme = &app_state.theme;
+    let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
+    let [header_area, content_area] = vertical.areas(frame.area());
 
-    let aggregate_score = fixture.try_get_score(&ScoreKey::Aggregate);
-
-    let (state_text, state_style, center_text, center_style) = match fixture.period {
-        // first half and second half
-        1 | 2 => {
-            let unconfimed_score = fixture.try_get_score(&ScoreKey::TotalUnconfirmed);
-
-            let score = if let Some(score) = unconfimed_score {
-                if let Some(aggregate_score) = aggregate_score {
-                    format!(
-                        "{}[{}] - {}[{}] (*)",
-                        score.home, aggregate_score.home, score.away, aggregate_score.away
-                    )
-                } else {
-                    format!("{} - {} (*)", score.home, score.away)
-                }
-            } else {
-                let current_score = fixture.try_get_score(&ScoreKey::Total).expect(
-                    "period is 1 or 2 (first of second half) but no total score was provided",
-                );
-                if let Some(aggregate_score) = aggregate_score {
-                    format!(
-                        "{} [{}] - {} [{}] (*)",
-                        current_score.home,
-                        aggregate_score.home,
-                        current_score.away,
-                        aggregate_score.away
-                    )
-                } else {
-                    format!("{} - {}", current_score.home, current_score.away)
-                }
-            };
+    let live_count = all_live_rows
+        .iter()
+        .filter(|row| matches!(row, AllLiveRow::Match(_)))
+        .count();
 
-            let time = &fixture.time.unwrap_or(0);
-            (
-                format!("{time}'"),
-                Style::new().red().bold().italic(),
-                score,
-                Style::new().red().bold().italic(),
-            )
+    let live_scores = &mut render_state.live_scores;
+    live_scores.date_nav = render_title(
+        frame,
+        app_state,
+        header_area,
+        date,
+        format!("All live ({live_count})"),
+        true,
+    )
+    .unwrap_or_default();
+    live_scores.tab_areas.clear();
+    live_scores.sidebar_area = Rect::default();
+
+    let block = Block::bordered()
+        .border_set(symbols::border::DOUBLE)
+        .border_style(theme.border);
+
+    if all_live_rows.is_empty() {
+        live_scores.table_area = Rect::default();
+        frame.render_widget(
+            Paragraph::new("No matches in play")
+                .italic()
+                .alignment(Alignment::Center)
+                .block(block),
+            content_area,
+        );
+        return;
+    }
+
+    // alternate row colours restart for each competition, as they do on the competition tabs
+    let mut idx = 0;
+    let rows = all_live_rows
+        .iter()
+        .map(|row| match row {
+            AllLiveRow::Competition(competition_name) => {
+                idx = 0;
+                Row::new(vec![
+                    Cell::new(""),
+                    Cell::new(""),
+                    Cell::new(competition_name.as_str()),
+                ])
+                .style(Style::new().fg(theme.accent).bold())
+            }
+            AllLiveRow::Match(fixture) => {
+                check_for_anomalies(fixture, &mut render_state.anomalies);
+                let row = build_row(app_state, idx, fixture);
+                idx += 1;
+                row
+            }
+        })
+        .collect::<Vec<_>>();
+
+    render_fixtures_table(frame, app_state, render_state, rows, block, content_area);
+}
+
+fn render_fixtures_table(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    rows: Vec<Row>,
+    block: Block,
+    area: Rect,
+) {
+    let table =
+        Table::new(rows, FIXTURE_COLUMNS).row_highlight_style(app_state.theme.selected_row_style());
+
+    render_state.live_scores.table_area = block.inner(area);
+    frame.render_stateful_widget(
+        table.block(block),
+        area,
+        &mut render_state.live_scores.table_state,
+    );
+}
+
+/// Compact mode, every competition truncated to a few characters
+fn draw_tabs(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    data_grouped: &[(String, Vec<Match>)],
+    area: Rect,
+) {
+    let titles = data_grouped
+        .iter()
+        // truncate by characters, truncating the string by bytes would panic on competition
+        // names with multi byte characters
+        .map(|(key, _)| Line::from(key.chars().take(5).collect::<String>()))
+        .collect::<Vec<_>>();
+
+    render_state.live_scores.tab_areas = tab_areas(area, &titles);
+
+    Tabs::new(titles)
+        .highlight_style(app_state.theme.tab_highlight_style())
+        .select(Some(render_state.live_scores.selected_tab))
+        .padding("", "")
+        .render(area, frame.buffer_mut());
+}
+
+/// Lists the competitions with a header for each country, the number of matches and a marker for
+/// competitions with live matches. Competitions stay in the same order as the tabs, so a country
+/// can appear more than once when one of its competitions has a higher priority
+fn draw_sidebar(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    data_grouped: &[(String, Vec<Match>)],
+    area: Rect,
+) {
+    let theme = &app_state.theme;
+    let mut items = Vec::new();
+    let mut item_tabs = Vec::new();
+    let mut previous_country = None;
+
+    for (tab, (key, matches)) in data_grouped.iter().enumerate() {
+        let (country, competition_name) = match matches.first() {
+            Some(m) if key != FAVOURITES_TAB => {
+                (Some(&m.comp.country.full_name), m.comp.name.as_str())
+            }
+            _ => (None, key.as_str()),
+        };
+
+        if let Some(country) = country
+            && previous_country != Some(country)
+        {
+            items.push(ListItem::new(
+                Line::from(country.clone()).bold().fg(theme.title),
+            ));
+            item_tabs.push(None);
         }
-        // yet to start
-        16 => {
-            let start_time = &fixture.date.with_timezone(&Local).time().format("%H:%M");
+        previous_country = country;
 
-            let center_text = if let Some(aggregate) = aggregate_score {
-                format!("[{}] v [{}]", aggregate.home, aggregate.away)
-            } else {
-                "v".to_string()
-            };
-            (
-                format!("{start_time}"),
-                Style::new().fg(text_color).bold(),
-                center_text,
-                Style::new().fg(text_color).bold(),
-            )
+        let mut line = Line::from(vec![
+            Span::raw(format!(" {competition_name} ")),
+            Span::raw(format!("({})", matches.len())).italic(),
+        ]);
+        if matches.iter().any(Match::is_live) {
+            line.push_span(Span::raw(" ●").fg(theme.live));
         }
-        // half time
-        10 => {
-            let center_text = if let Some(ht_score) = fixture.try_get_score(&ScoreKey::Ht) {
-                if let Some(aggregate_score) = fixture.try_get_score(&ScoreKey::Aggregate) {
-                    format!(
-                        "{} [{}] - {} [{}]",
-                        ht_score.home, aggregate_score.home, ht_score.away, aggregate_score.away
-                    )
-                } else {
-                    format!("{} - {}", ht_score.home, ht_score.away)
-                }
-            } else {
-                "???".to_string()
-            };
+        items.push(ListItem::new(line));
+        item_tabs.push(Some(tab));
+    }
+
+    let selected_item = item_tabs
+        .iter()
+        .position(|tab| *tab == Some(render_state.live_scores.selected_tab));
+
+    let block = Block::bordered()
+        .title("Competitions")
+        .border_style(theme.border);
+    let list = List::new(items)
+        .highlight_style(theme.selected_row_style())
+        .block(block.clone());
 
+    let live_scores = &mut render_state.live_scores;
+    live_scores.sidebar_state.select(selected_item);
+    live_scores.sidebar_area = block.inner(area);
+    live_scores.sidebar_tabs = item_tabs;
+    frame.render_stateful_widget(list, area, &mut live_scores.sidebar_state);
+}
+
+/// where each tab is drawn, matching the layout of [`Tabs`] with no padding and a one column
+/// divider
+fn tab_areas(area: Rect, titles: &[Line]) -> Vec<Rect> {
+    let mut x = area.x;
+    titles
+        .iter()
+        .map(|title| {
+            let width = title.width() as u16;
+            let tab_area = Rect::new(x, area.y, width, 1).intersection(area);
+            x = x.saturating_add(width + 1);
+            tab_area
+        })
+        .collect()
+}
+
+pub(in crate::ui::pages) fn build_row<'a>(
+    app_state: &State,
+    idx: usize,
+    fixture: &'a Match,
+) -> Row<'a> {
+    let theme = &app_state.theme;
+    let favourites = &app_state.favourites;
+    let (text_color, row_color) = theme.row_colors(idx);
+
+    let summary = fixture.score_summary();
+
+    let period = fixture.current_period();
+    let state_text = status_label(fixture, &app_state.clock);
+
+    let (state_style, center_text, center_style) = match period {
+        _ if fixture.status.is_disrupted() => {
+            let center_text = match fixture.status {
+                Status::Postponed | Status::Cancelled => "v".to_string(),
+                _ => format_score(&summary),
+            };
             (
-                "ht".to_string(),
-                Style::new().red().bold(),
+                Style::new().fg(theme.warning).bold(),
                 center_text,
-                Style::new().red().bold(),
+                Style::new().fg(text_color).bold(),
             )
         }
-        // full time
-        14 => {
-            let current_score = fixture
-                .try_get_score(&ScoreKey::Total)
-                .expect("period was 14 (full time) but no full time score was provided");
-
-            let score = if let Some(aggregate_score) = aggregate_score {
-                format!(
-                    "{} [{}] - {} [{}]",
-                    current_score.home,
-                    aggregate_score.home,
-                    current_score.away,
-                    aggregate_score.away
-                )
+        _ if period.is_playing() => (
+            Style::new().fg(theme.live).bold().italic(),
+            format_score(&summary),
+            Style::new().fg(theme.live).bold().italic(),
+        ),
+        _ if period.is_break() => (
+            Style::new().fg(theme.live).bold(),
+            format_score(&summary),
+            Style::new().fg(theme.live).bold(),
+        ),
+        Period::PreMatch => {
+            let center_text = if let Some(aggregate) = summary.aggregate {
+                format!("[{}] v [{}]", aggregate.home, aggregate.away)
             } else {
-                format!("{} - {}", current_score.home, current_score.away)
+                "v".to_string()
             };
-
             (
-                "ft".to_string(),
                 Style::new().fg(text_color).bold(),
-                score,
-                Style::new().fg(text_color).bold(),
-            )
-        }
-        _ => {
-            tracing::error!("got handled period for fixtrue: {fixture:?}");
-            (
-                "?".to_string(),
+                center_text,
                 Style::new().fg(text_color).bold(),
-                "?".to_string(),
-                Style::new().red().bold(),
             )
         }
+        Period::FullTime => (
+            Style::new().fg(text_color).bold(),
+            format_score(&summary),
+            Style::new().fg(text_color).bold(),
+        ),
+        _ => (
+            Style::new().fg(text_color).bold(),
+            "?".to_string(),
+            Style::new().fg(theme.live).bold(),
+        ),
     };
 
     Row::new(vec![
@@ -229,21 +388,29 @@ fn build_row<'a>(idx: usize, fixture: &'a Match) -> Row<'a> {
                 .alignment(Alignment::Left)
                 .style(state_style),
         ),
-        format_team_name(&fixture.home, Alignment::Right, text_color),
+        format_team_name(favourites, &fixture.home, Alignment::Right, text_color),
         Cell::new(
             Text::from(center_text)
                 .alignment(Alignment::Center)
                 .style(center_style),
         ),
-        format_team_name(&fixture.away, Alignment::Left, text_color),
+        format_team_name(favourites, &fixture.away, Alignment::Left, text_color),
     ])
     .style(Style::new().bg(row_color))
 }
 
-fn format_team_name<'a>(team: &'a Team, alligment: Alignment, text_color: Color) -> Cell<'a> {
-    Cell::new(
-        Text::from(team.name.clone().unwrap_or("tbc".into()))
-            .alignment(alligment)
-            .style(text_color),
-    )
+/// favourite teams are marked with a star on the side nearest the score
+fn format_team_name<'a>(
+    favourites: &Favourites,
+    team: &'a Team,
+    alligment: Alignment,
+    text_color: Color,
+) -> Cell<'a> {
+    let name = team.name.clone().unwrap_or("tbc".into());
+    let name = match (favourites.is_favourite_team(team), alligment) {
+        (true, Alignment::Right) => format!("{name} ★"),
+        (true, _) => format!("★ {name}"),
+        (false, _) => name,
+    };
+    Cell::new(Text::from(name).alignment(alligment).style(text_color))
 }
@@ -1,35 +1,56 @@
-use chrono::{Local, NaiveDate};
-use gegen_data::types::{
-    Card, CardEvent, Event, GoalEvent, GoalType, Match, PenaltyEvent, ScoreKey, SubEvent, VAREvent,
+use chrono::NaiveDate;
+use gegen_data::{
+    shootout::{REGULATION_KICKS, Shootout},
+    timeline::TimelineEvent,
+    types::{
+        Card, CardEvent, Event, GoalEvent, GoalType, Match, MatchId, PenaltyEvent, PenaltyOutcome,
+        Period, Side, Status, SubEvent, TeamId, VAREvent,
+    },
 };
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Rect},
-    style::{Color, Stylize},
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::Stylize,
     symbols,
-    text::Text,
+    text::{Line, Text},
     widgets::{Block, Cell, Paragraph, Row, Table},
 };
 
-use crate::{PageRenderStates, State};
+use crate::{PageRenderStates, State, ui::theme::Theme};
 
-use super::shared::{render_loading, render_title};
+use super::shared::{
+    check_for_anomalies, format_score, render_loading, render_title, status_label,
+};
 
 pub(crate) fn draw(
     frame: &mut Frame,
     app_state: &State,
     render_state: &mut PageRenderStates,
     date: &NaiveDate,
-    match_id: &str,
-    competition_name: &String,
+    match_id: &MatchId,
+    competition_name: &str,
 ) {
     let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
 
     let [header_area, inner_area] = vertical.areas(frame.area());
 
-    let block = Block::bordered()
+    // the match is looked up by id as it can be opened from the favourites tab as well as its
+    // competition's tab
+    let match_data = app_state.find_match(match_id);
+
+    let mut block = Block::bordered()
         .border_set(symbols::border::DOUBLE)
-        .border_style(Color::Green);
+        .border_style(app_state.theme.border);
+    if let Some(match_data) = &match_data {
+        block = block.title_bottom(
+            Line::from(format!(
+                " updated {} ",
+                app_state.clock.format_time_with_seconds(match_data.updated)
+            ))
+            .right_aligned()
+            .italic(),
+        );
+    }
 
     frame.render_widget(block, inner_area);
 
@@ -51,40 +72,20 @@ pub(crate) fn draw(
 
     render_title(
         frame,
+        app_state,
         header_area,
         date,
-        &app_state.today,
-        competition_name.clone(),
+        competition_name.to_string(),
+        false,
     );
 
-    match app_state.get_grouped_data() {
-        Some(data_grouped) => {
-            let Some(competition_data) = data_grouped
-                .iter()
-                .find(|(comp_name, _)| comp_name == competition_name)
-            else {
-                render_loading(
-                    frame,
-                    inner_area,
-                    &mut render_state.match_overview.throbber_state,
-                );
-                return;
-            };
-
-            let Some(match_data) = competition_data.1.iter().find(|m| m.id == match_id) else {
-                render_loading(
-                    frame,
-                    inner_area,
-                    &mut render_state.match_overview.throbber_state,
-                );
-                return;
-            };
-
+    match match_data {
+        Some(ref match_data) => {
             let layout = Layout::vertical([Constraint::Percentage(10), Constraint::Percentage(90)]);
             let [header_area, events_area] = layout.areas(inner_area);
             let layout = Layout::horizontal([
                 Constraint::Percentage(50),
-                Constraint::Min(20),
+                Constraint::Min(30),
                 Constraint::Percentage(50),
             ]);
 
@@ -96,19 +97,44 @@ pub(crate) fn draw(
             let [_, home_team_area] = layout.areas(left_header);
             let [_, away_team_area] = layout.areas(right_header);
 
+            check_for_anomalies(match_data, &mut render_state.anomalies);
             draw_overview(
                 frame,
+                app_state,
                 match_data,
                 time_area,
                 home_team_area,
                 score_area,
                 away_team_area,
             );
-            draw_events(frame, match_data, events_area, render_state);
+            let events_area = match match_data.shootout() {
+                Some(shootout) => {
+                    let layout = Layout::vertical([Constraint::Length(5), Constraint::Min(0)]);
+                    let [shootout_area, events_area] = layout.areas(events_area);
+                    draw_shootout(
+                        frame,
+                        &app_state.theme,
+                        match_data,
+                        &shootout,
+                        shootout_area,
+                    );
+                    events_area
+                }
+                None => events_area,
+            };
+            draw_events(
+                frame,
+                &app_state.theme,
+                match_data,
+                events_area,
+                render_state,
+            );
         }
         None => {
+            render_state.match_overview.events_area = Rect::default();
             render_loading(
                 frame,
+                &app_state.theme,
                 inner_area,
                 &mut render_state.match_overview.throbber_state,
             );
@@ -118,12 +144,14 @@ pub(crate) fn draw(
 
 fn draw_overview(
     frame: &mut Frame,
+    app_state: &State,
     match_data: &Match,
     time_area: Rect,
     home_team_area: Rect,
     score_area: Rect,
     away_team_area: Rect,
 ) {
+    let theme = &app_state.theme;
     let home_team_para = Paragraph::new(match_data.home.clone().name.unwrap_or("tbc".into()))
         .bold()
         .centered();
@@ -134,93 +162,54 @@ fn draw_overview(
     frame.render_widget(home_team_para, home_team_area);
     frame.render_widget(away_team_para, away_team_area);
 
-    let unconfimed_score = match_data.try_get_score(&ScoreKey::TotalUnconfirmed);
-    let aggregated_score = match_data.try_get_score(&ScoreKey::Aggregate);
-
-    match match_data.period {
-        // first or second half
-        1 | 2 => {
-            let score = if let Some(score) = unconfimed_score {
-                if let Some(aggregate_score) = aggregated_score {
-                    format!(
-                        "{} [{}] - {} [{}](*)",
-                        score.home, aggregate_score.home, score.away, aggregate_score.away
-                    )
-                } else {
-                    format!("{} - {} (*)", score.home, score.away)
-                }
-            } else {
-                let current_score = match_data.try_get_score(&ScoreKey::Total).expect(
-                    "period is 1 or 2 (first of second half) but no total score was provided",
-                );
-                if let Some(aggregate_score) = aggregated_score {
-                    format!(
-                        "{} [{}] - {} [{}]",
-                        current_score.home,
-                        aggregate_score.home,
-                        current_score.away,
-                        aggregate_score.away
-                    )
-                } else {
-                    format!("{} - {}", current_score.home, current_score.away)
-                }
-            };
+    let summary = match_data.score_summary();
+    let period = match_data.current_period();
 
-            let match_time = match_data.time.unwrap_or_default();
-            let match_time_string = format!("{match_time}'");
-            let match_time_para = Paragraph::new(match_time_string)
-                .centered()
-                .red()
-                .bold()
-                .italic();
+    let time_para = Paragraph::new(status_label(match_data, &app_state.clock))
+        .centered()
+        .bold();
 
-            let score_para = Paragraph::new(score).red().italic().bold().centered();
-            frame.render_widget(match_time_para, time_area);
+    match period {
+        _ if match_data.status.is_disrupted() => {
+            let label = status_label(match_data, &app_state.clock);
+            let label = match (match_data.status, match_data.time) {
+                (Status::Suspended | Status::Abandoned, Some(time)) => {
+                    format!("{label} at {time}'")
+                }
+                _ => label,
+            };
+            let score_text = match match_data.status {
+                Status::Postponed | Status::Cancelled => "vs".to_string(),
+                _ => format_score(&summary),
+            };
+            let p = Paragraph::new(label).centered().bold().fg(theme.warning);
+            let score_para = Paragraph::new(score_text).bold().centered();
+            frame.render_widget(p, time_area);
             frame.render_widget(score_para, score_area);
         }
-        // penalties
-        5 => {
-            let p = Paragraph::new("penalties").centered().red().bold().italic();
-            frame.render_widget(p, time_area);
+        _ if period.is_playing() => {
+            let score_para = Paragraph::new(format_score(&summary))
+                .fg(theme.live)
+                .italic()
+                .bold()
+                .centered();
+            frame.render_widget(time_para.fg(theme.live).italic(), time_area);
+            frame.render_widget(score_para, score_area);
         }
-        // half time
-        10 => {
-            let scores = match_data.score.clone().unwrap_or_default();
-            let ht_score = scores.get(&ScoreKey::Ht).expect("No half time score");
-            let ht_score = format!("{} - {}", ht_score.home, ht_score.away);
-
-            let score_para = Paragraph::new(ht_score).bold().centered();
-
-            let p = Paragraph::new("ht").centered().bold().red();
-            frame.render_widget(p, time_area);
+        _ if period.is_break() => {
+            let score_para = Paragraph::new(format_score(&summary)).bold().centered();
+            frame.render_widget(time_para.fg(theme.live), time_area);
             frame.render_widget(score_para, score_area);
         }
-        // full time
-        14 => {
-            let scores = match_data.score.clone().unwrap_or_default();
-            let ft_score = scores.get(&ScoreKey::Total).expect("No full time score");
-
-            let ft_score = if let Some(aggregate_score) = aggregated_score {
-                format!(
-                    "{} [{}] - {} [{}]",
-                    ft_score.home, aggregate_score.home, ft_score.away, aggregate_score.away
-                )
-            } else {
-                format!("{} - {}", ft_score.home, ft_score.away)
-            };
-
-            let score_para = Paragraph::new(ft_score).bold().centered();
-            let p = Paragraph::new("ft").centered().bold();
-            frame.render_widget(p, time_area);
+        Period::FullTime => {
+            let score_para = Paragraph::new(format_score(&summary)).bold().centered();
+            frame.render_widget(time_para, time_area);
             frame.render_widget(score_para, score_area);
         }
-        // yet to start
-        16 => {
-            let start_time = &match_data.date.with_timezone(&Local).time().format("%H:%M");
-            let p = Paragraph::new(format!("{start_time}")).centered().bold();
-            frame.render_widget(p, time_area);
+        Period::PreMatch => {
+            frame.render_widget(time_para, time_area);
 
-            let vs_text = if let Some(aggregate_score) = aggregated_score {
+            let vs_text = if let Some(aggregate_score) = summary.aggregate {
                 format!("[{}] vs [{}]", aggregate_score.home, aggregate_score.away)
             } else {
                 "vs".to_string()
@@ -228,23 +217,27 @@ fn draw_overview(
             let score_para = Paragraph::new(vs_text).bold().centered();
             frame.render_widget(score_para, score_area);
         }
-        _ => {}
+        _ => {
+            let score_para = Paragraph::new(format_score(&summary)).bold().centered();
+            frame.render_widget(time_para, time_area);
+            frame.render_widget(score_para, score_area);
+        }
     }
 }
 
 fn draw_events(
     frame: &mut Frame,
+    theme: &Theme,
     match_data: &Match,
     events_area: Rect,
     render_state: &mut PageRenderStates,
 ) {
-    let Some(events) = &match_data.events else {
-        return;
-    };
-
-    let event_rows = events
-        .iter()
-        .map(|event| render_event(event, match_data.home.id.as_ref()))
+    // shootout kicks are drawn separately by `draw_shootout`
+    let event_rows = match_data
+        .timeline()
+        .into_iter()
+        .filter(|event| !(matches!(event, Event::Pen(_)) && event.period() == Period::Penalties))
+        .map(|event| render_event(theme, event, match_data.home.id.as_ref()))
         .collect::<Vec<_>>();
 
     let table = Table::new(
@@ -257,33 +250,106 @@ fn draw_events(
             Constraint::Percentage(50),
         ],
     );
+    render_state.match_overview.events_area = events_area;
     frame.render_stateful_widget(
         table,
         events_area,
-        &mut render_state.live_scores.table_state,
+        &mut render_state.match_overview.events_table_state,
     );
 }
 
+fn draw_shootout(
+    frame: &mut Frame,
+    theme: &Theme,
+    match_data: &Match,
+    shootout: &Shootout,
+    area: Rect,
+) {
+    let rounds = shootout.rounds().max(REGULATION_KICKS);
+    let winner = shootout.winner();
+
+    let header = std::iter::once(Cell::new(""))
+        .chain((1..=rounds).map(|round| Cell::new(Text::from(round.to_string()).centered())))
+        .chain(std::iter::once(Cell::new("")));
+
+    let rows = [
+        (Side::Home, &match_data.home.name),
+        (Side::Away, &match_data.away.name),
+    ]
+    .map(|(side, team_name)| {
+        let mut kicks = shootout
+            .kicks_for(side)
+            .map(|kick| match kick.outcome {
+                PenaltyOutcome::Scored => "✅",
+                PenaltyOutcome::Saved | PenaltyOutcome::Missed => "❌",
+            })
+            .collect::<Vec<_>>();
+        kicks.resize(rounds, "·");
+
+        let team_name = Text::from(team_name.clone().unwrap_or("tbc".into())).right_aligned();
+        let tally = Text::from(shootout.score().for_side(side).to_string()).bold();
+
+        let row = Row::new(
+            std::iter::once(Cell::new(team_name))
+                .chain(
+                    kicks
+                        .into_iter()
+                        .map(|kick| Cell::new(Text::from(kick).centered())),
+                )
+                .chain(std::iter::once(Cell::new(tally))),
+        );
+
+        if winner == Some(side) {
+            row.bold().fg(theme.highlight)
+        } else {
+            row
+        }
+    });
+
+    let name_width = [&match_data.home.name, &match_data.away.name]
+        .into_iter()
+        .map(|name| name.as_ref().map_or(3, |name| name.chars().count()))
+        .max()
+        .unwrap_or_default() as u16;
+
+    let widths = std::iter::once(Constraint::Length(name_width))
+        .chain(std::iter::repeat_n(Constraint::Length(3), rounds))
+        .chain([Constraint::Length(3)]);
+
+    // each column is followed by a single space of padding
+    let table_width = name_width + 4 * (rounds as u16 + 1);
+    let [area] = Layout::horizontal([Constraint::Length(table_width)])
+        .flex(Flex::Center)
+        .areas(area);
+
+    let title = if shootout.is_sudden_death() {
+        "Penalties (sudden death)"
+    } else {
+        "Penalties"
+    };
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).italic())
+        .block(Block::new().title(title).title_alignment(Alignment::Center));
+
+    frame.render_widget(table, area);
+}
+
 enum EventSide {
     Home,
     Away,
 }
 
-fn render_event(event: &Event, home_team_id: Option<&String>) -> Row<'static> {
+fn render_event(theme: &Theme, event: &Event, home_team_id: Option<&TeamId>) -> Row<'static> {
     let (emoji, text, event_side) = match event {
         Event::Sub(sub_event) => build_sub_event(sub_event, home_team_id),
-        Event::Goal(goal_event) => build_goal_event(goal_event, home_team_id),
+        Event::Goal(goal_event) => build_goal_event(theme, goal_event, home_team_id),
         Event::Card(card_event) => build_card_event(card_event, home_team_id),
         Event::Var(var_event) => build_var_event(var_event, home_team_id),
         Event::Pen(penalty_event) => build_penalty_event(penalty_event, home_team_id),
     };
 
-    let time = event
-        .get_time_str()
-        .map(|a| a.to_string())
-        .unwrap_or("".to_string());
-
-    let time = Cell::new(Text::from(time));
+    let time = Cell::new(Text::from(event.match_time().to_string()));
     let emoji = Cell::new(emoji);
 
     let v = match event_side {
@@ -302,7 +368,7 @@ fn render_event(event: &Event, home_team_id: Option<&String>) -> Row<'static> {
 
 fn build_penalty_event(
     penalty_event: &PenaltyEvent,
-    home_team_id: Option<&String>,
+    home_team_id: Option<&TeamId>,
 ) -> (&'static str, Text<'static>, EventSide) {
     let event_side = if Some(&penalty_event.team_id) == home_team_id {
         EventSide::Home
@@ -311,15 +377,9 @@ fn build_penalty_event(
     };
 
     let (emoji, text) = match penalty_event.outcome {
-        gegen_data::types::PenaltyOutcome::Saved => {
-            ("❌", format!("{}: Saved", penalty_event.player_name))
-        }
-        gegen_data::types::PenaltyOutcome::Scored => {
-            ("✅", format!("{}: Scored", penalty_event.player_name))
-        }
-        gegen_data::types::PenaltyOutcome::Missed => {
-            ("❌", format!("{}: Missed", penalty_event.player_name))
-        }
+        PenaltyOutcome::Saved => ("❌", format!("{}: Saved", penalty_event.player_name)),
+        PenaltyOutcome::Scored => ("✅", format!("{}: Scored", penalty_event.player_name)),
+        PenaltyOutcome::Missed => ("❌", format!("{}: Missed", penalty_event.player_name)),
     };
 
     (emoji, Text::from(text), event_side)
@@ -327,7 +387,7 @@ fn build_penalty_event(
 
 fn build_var_event(
     var_event: &VAREvent,
-    home_team_id: Option<&String>,
+    home_team_id: Option<&TeamId>,
 ) -> (&'static str, Text<'static>, EventSide) {
     let event_side = if Some(&var_event.team_id) == home_team_id {
         EventSide::Home
@@ -348,7 +408,7 @@ fn build_var_event(
 
 fn build_card_event(
     card_event: &CardEvent,
-    home_team_id: Option<&String>,
+    home_team_id: Option<&TeamId>,
 ) -> (&'static str, Text<'static>, EventSide) {
     let event_side = if Some(&card_event.team_id) == home_team_id {
         EventSide::Home
@@ -373,7 +433,7 @@ fn build_card_event(
 
 fn build_sub_event(
     sub_event: &SubEvent,
-    home_team_id: Option<&String>,
+    home_team_id: Option<&TeamId>,
 ) -> (&'static str, Text<'static>, EventSide) {
     let event_side = if Some(&sub_event.team_id) == home_team_id {
         EventSide::Home
@@ -390,8 +450,9 @@ fn build_sub_event(
 }
 
 fn build_goal_event(
+    theme: &Theme,
     goal_event: &GoalEvent,
-    home_team_id: Option<&String>,
+    home_team_id: Option<&TeamId>,
 ) -> (&'static str, Text<'static>, EventSide) {
     let (text, event_side) = match goal_event.goal_type {
         GoalType::Goal => {
@@ -421,7 +482,11 @@ fn build_goal_event(
             (format_goal_text(goal_event), event_side)
         }
     };
-    ("⚽", Text::from(text).yellow().italic(), event_side)
+    (
+        "⚽",
+        Text::from(text).fg(theme.highlight).italic(),
+        event_side,
+    )
 }
 
 fn format_goal_text(goal_event: &GoalEvent) -> String {
@@ -1,3 +1,7 @@
+pub(crate) mod competition;
 pub(crate) mod live_scores;
 pub(crate) mod match_overview;
 pub(in crate::ui::pages) mod shared;
+pub(crate) mod standings;
+pub(crate) mod team;
+pub(crate) mod week;
@@ -1,19 +1,34 @@
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, Utc};
+use gegen_data::types::{Match, MatchResult, Period, ScoreSummary, Status};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
-    style::Stylize,
+    style::{Color, Stylize},
+    text::Line,
     widgets::Paragraph,
 };
 use throbber_widgets_tui::ThrobberState;
 
+use crate::{
+    State,
+    clock::Clock,
+    state::{Anomaly, AnomalyLog, DateNavAreas},
+    ui::theme::Theme,
+};
+
+/// Renders the date, the current time and the tab title, with `day_arrows` the date is surrounded
+/// by arrows that can be clicked to move between days and their areas are returned. The timezone
+/// is named after the time unless it's the local timezone
 pub(in crate::ui::pages) fn render_title(
     frame: &mut Frame,
+    app_state: &State,
     area: Rect,
     date: &NaiveDate,
-    todays_date: &NaiveDate,
     tab_title: String,
-) {
+    day_arrows: bool,
+) -> Option<DateNavAreas> {
+    let theme = &app_state.theme;
+    let clock = &app_state.clock;
     let layout = Layout::horizontal([
         Constraint::Min(0),
         Constraint::Percentage(50),
@@ -22,45 +37,165 @@ pub(in crate::ui::pages) fn render_title(
     ]);
     let [_, left_area, right_area, _] = layout.areas(area);
     let weekday = date.weekday();
-    let title = if date == todays_date {
+    let title = if *date == app_state.today {
         format!("Today ({weekday} - {date})")
     } else {
         format!("{weekday} - {date}")
     };
+    let title = if day_arrows {
+        format!("< {title} >")
+    } else {
+        title
+    };
+    let title_width = Line::raw(title.as_str()).width() as u16;
+
+    let time = match clock.timezone_label() {
+        Some(timezone) => format!(" {} {timezone}", clock.format_time(Utc::now())),
+        None => format!(" {}", clock.format_time(Utc::now())),
+    };
 
     frame.render_widget(
-        Paragraph::new(title)
-            .light_green()
-            .alignment(Alignment::Left)
-            .bold(),
+        Paragraph::new(Line::from(vec![title.bold(), time.into()]))
+            .fg(theme.title)
+            .alignment(Alignment::Left),
         left_area,
     );
 
     frame.render_widget(
         Paragraph::new(tab_title)
-            .light_green()
             .alignment(Alignment::Right)
-            .magenta()
+            .fg(theme.accent)
             .bold(),
         right_area,
     );
+
+    day_arrows.then(|| {
+        let arrow = |x| Rect::new(x, left_area.y, 1, 1).intersection(left_area);
+        DateNavAreas {
+            previous_day: arrow(left_area.x),
+            next_day: arrow(left_area.x + title_width.saturating_sub(1)),
+        }
+    })
 }
 
 // TODO: make throbber render in centre of page
 pub(in crate::ui::pages) fn render_loading(
     frame: &mut Frame,
+    theme: &Theme,
     area: Rect,
     throbber_state: &mut ThrobberState,
 ) {
     let full = throbber_widgets_tui::Throbber::default()
         .label("loading...")
-        .style(ratatui::style::Style::default().fg(ratatui::style::Color::Cyan))
+        .style(ratatui::style::Style::default().fg(theme.throbber_label))
         .throbber_style(
             ratatui::style::Style::default()
-                .fg(ratatui::style::Color::Magenta)
+                .fg(theme.throbber)
                 .add_modifier(ratatui::style::Modifier::BOLD),
         )
         .throbber_set(throbber_widgets_tui::ARROW)
         .use_type(throbber_widgets_tui::WhichUse::Spin);
     frame.render_stateful_widget(full, area, throbber_state);
 }
+
+/// formats the current score, including the aggregate and shootout scores when there are any.
+/// Unconfirmed scores are marked with `(*)`
+pub(in crate::ui::pages) fn format_score(summary: &ScoreSummary) -> String {
+    let Some(score) = summary.current else {
+        return "???".to_string();
+    };
+
+    let score_text = if let Some(aggregate_score) = summary.aggregate {
+        format!(
+            "{} [{}] - {} [{}]",
+            score.home, aggregate_score.home, score.away, aggregate_score.away
+        )
+    } else {
+        format!("{} - {}", score.home, score.away)
+    };
+
+    let score_text = if let Some(penalties) = summary.penalties {
+        format!("{score_text} ({} - {} p)", penalties.home, penalties.away)
+    } else {
+        score_text
+    };
+
+    if summary.unconfirmed {
+        format!("{score_text} (*)")
+    } else {
+        score_text
+    }
+}
+
+/// short description of the state of the match, e.g. the minute, `ht`, `aet` or the kick off time
+pub(in crate::ui::pages) fn status_label(match_data: &Match, clock: &Clock) -> String {
+    if let Some(label) = disruption_label(match_data.status) {
+        return label.to_string();
+    }
+
+    let time = match_data.time.unwrap_or_default();
+
+    match match_data.current_period() {
+        Period::FirstHalf | Period::SecondHalf => format!("{time}'"),
+        Period::ExtraTimeFirstHalf | Period::ExtraTimeSecondHalf => format!("{time}' et"),
+        Period::Penalties => "pens".to_string(),
+        Period::HalfTime => "ht".to_string(),
+        Period::ExtraTimeBreak => "end 90".to_string(),
+        Period::ExtraTimeHalfTime => "et ht".to_string(),
+        Period::PenaltiesBreak => "end et".to_string(),
+        Period::FullTime => {
+            let summary = match_data.score_summary();
+            if summary.penalties.is_some() {
+                "pens".to_string()
+            } else if summary.extra_time.is_some() {
+                "aet".to_string()
+            } else {
+                "ft".to_string()
+            }
+        }
+        Period::PreMatch => clock.format_time(match_data.date),
+        Period::Unknown(_) => "?".to_string(),
+    }
+}
+
+/// label for matches that won't be played to completion as scheduled
+pub(in crate::ui::pages) fn disruption_label(status: Status) -> Option<&'static str> {
+    match status {
+        Status::Postponed => Some("postponed"),
+        Status::Suspended => Some("suspended"),
+        Status::Abandoned => Some("abandoned"),
+        Status::Cancelled => Some("cancelled"),
+        Status::Played | Status::Fixture | Status::Playing | Status::Unknown => None,
+    }
+}
+
+/// the letter and colour for a result in a form guide
+pub(in crate::ui::pages) fn result_letter(
+    theme: &Theme,
+    result: MatchResult,
+) -> (&'static str, Color) {
+    match result {
+        MatchResult::Win => ("W", theme.win),
+        MatchResult::Draw => ("D", theme.draw),
+        MatchResult::Loss => ("L", theme.loss),
+    }
+}
+
+/// logs anything about the match that will have to be rendered with a placeholder
+pub(in crate::ui::pages) fn check_for_anomalies(match_data: &Match, anomalies: &mut AnomalyLog) {
+    let period = match_data.current_period();
+
+    if let Period::Unknown(period) = period {
+        anomalies.log_once(match_data, Anomaly::UnknownPeriod(period));
+    }
+
+    if match_data.status == Status::Unknown {
+        anomalies.log_once(match_data, Anomaly::UnknownStatus);
+    }
+
+    let expects_score = (period.is_in_play() || period == Period::FullTime)
+        && !matches!(match_data.status, Status::Postponed | Status::Cancelled);
+    if expects_score && match_data.current_score().is_none() {
+        anomalies.log_once(match_data, Anomaly::MissingScore);
+    }
+}
@@ -0,0 +1,211 @@
+use chrono::Days;
+use gegen_data::{
+    standings::{Standing, league_table},
+    types::{CompetitionId, TeamId},
+};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Span, Text},
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::{PageRenderStates, State};
+
+use super::shared::{render_loading, result_letter};
+
+pub(crate) fn draw(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    competition_id: &CompetitionId,
+    competition_name: &str,
+    highlighted: &[TeamId],
+) {
+    let theme = &app_state.theme;
+    let first = app_state
+        .today
+        .checked_sub_days(Days::new(render_state.standings.days))
+        .unwrap_or(app_state.today);
+
+    let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
+    let [header_area, content_area] = vertical.areas(frame.area());
+
+    let [title_area, days_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(header_area);
+    frame.render_widget(
+        Paragraph::new(format!(" {competition_name}"))
+            .fg(theme.title)
+            .bold(),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!("last {} days ", render_state.standings.days))
+            .alignment(Alignment::Right)
+            .fg(theme.accent)
+            .bold(),
+        days_area,
+    );
+
+    if !app_state.data.contains_key(&app_state.today) {
+        render_loading(
+            frame,
+            theme,
+            content_area,
+            &mut render_state.live_scores.throbber_state,
+        );
+        return;
+    }
+
+    let matches = app_state.competition_matches(competition_id);
+    let standings_config = &app_state.config.standings;
+    let tiebreakers = matches
+        .first()
+        .map_or(standings_config.tiebreakers.as_slice(), |m| {
+            standings_config.tiebreakers(&m.comp)
+        });
+    let table = league_table(&matches, tiebreakers);
+
+    let results = matches
+        .iter()
+        .filter(|m| m.is_finished() && !m.status.is_disrupted())
+        .collect::<Vec<_>>();
+    let mut block = Block::bordered()
+        .border_set(symbols::border::DOUBLE)
+        .border_style(theme.border);
+    if let Some(earliest) = results.iter().map(|m| m.date).min() {
+        block = block.title(
+            Line::from(format!(
+                " from {} {} since {} ",
+                results.len(),
+                if results.len() == 1 {
+                    "result"
+                } else {
+                    "results"
+                },
+                app_state.clock.date_of(earliest).format("%a %d %b")
+            ))
+            .fg(theme.title)
+            .bold(),
+        );
+    }
+    if !app_state.is_range_loaded(first, app_state.today) {
+        block = block.title_bottom(
+            Line::from(" loading more days... ")
+                .right_aligned()
+                .italic(),
+        );
+    }
+
+    if table.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No results found")
+                .italic()
+                .alignment(Alignment::Center)
+                .block(block),
+            content_area,
+        );
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "#", "Team", "P", "W", "D", "L", "GF", "GA", "GD", "Pts", "Form",
+        ]
+        .into_iter()
+        .map(|title| Cell::new(Text::from(title).alignment(alignment(title)))),
+    )
+    .style(Style::new().fg(theme.title).bold());
+
+    let rows = table
+        .iter()
+        .enumerate()
+        .map(|(idx, standing)| build_row(app_state, idx, standing, highlighted))
+        .collect::<Vec<_>>();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(3),
+            Constraint::Min(20),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(4),
+            Constraint::Length(4),
+            Constraint::Length(4),
+            Constraint::Length(4),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .row_highlight_style(theme.selected_row_style());
+
+    frame.render_stateful_widget(
+        table.block(block),
+        content_area,
+        &mut render_state.standings.table_state,
+    );
+}
+
+/// the team name and form are on the left, the numbers are lined up on the right
+fn alignment(title: &str) -> Alignment {
+    match title {
+        "Team" | "Form" => Alignment::Left,
+        _ => Alignment::Right,
+    }
+}
+
+fn build_row<'a>(
+    app_state: &State,
+    idx: usize,
+    standing: &Standing,
+    highlighted: &[TeamId],
+) -> Row<'a> {
+    let theme = &app_state.theme;
+    let (text_color, row_color) = theme.row_colors(idx);
+    // the teams of the match the table was opened from stand out
+    let text_style = if highlighted.contains(&standing.team_id) {
+        Style::new().fg(theme.highlight).bold()
+    } else {
+        Style::new().fg(text_color)
+    };
+
+    let number = |value: String| Cell::new(Text::from(value).alignment(Alignment::Right));
+    let form = Line::from(
+        standing
+            .form
+            .iter()
+            .map(|result| {
+                let (letter, color) = result_letter(theme, *result);
+                Span::raw(format!("{letter} ")).fg(color).bold()
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    Row::new(vec![
+        number((idx + 1).to_string()),
+        Cell::new(standing.team_name.clone()),
+        number(standing.played.to_string()),
+        number(standing.won.to_string()),
+        number(standing.drawn.to_string()),
+        number(standing.lost.to_string()),
+        number(standing.goals_for.to_string()),
+        number(standing.goals_against.to_string()),
+        number(match standing.goal_difference() {
+            goal_difference if goal_difference > 0 => format!("+{goal_difference}"),
+            goal_difference => goal_difference.to_string(),
+        }),
+        Cell::new(
+            Text::from(standing.points().to_string())
+                .alignment(Alignment::Right)
+                .bold(),
+        ),
+        Cell::new(form),
+    ])
+    .style(text_style.bg(row_color))
+}
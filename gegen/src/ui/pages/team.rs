@@ -0,0 +1,198 @@
+use chrono::Days;
+use gegen_data::{
+    standings::FORM_LENGTH,
+    types::{Match, MatchResult, Team, TeamId},
+};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Span, Text},
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::{PageRenderStates, State, state::TeamRow, ui::theme::Theme};
+
+use super::shared::{
+    check_for_anomalies, format_score, render_loading, result_letter, status_label,
+};
+
+pub(crate) fn draw(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    team_id: &TeamId,
+    team_name: &str,
+) {
+    let theme = &app_state.theme;
+    let days = Days::new(render_state.team.days);
+    let first = app_state
+        .today
+        .checked_sub_days(days)
+        .unwrap_or(app_state.today);
+    let last = app_state
+        .today
+        .checked_add_days(days)
+        .unwrap_or(app_state.today);
+
+    let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
+    let [header_area, content_area] = vertical.areas(frame.area());
+
+    let [title_area, days_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(header_area);
+    frame.render_widget(
+        Paragraph::new(format!(" {team_name}"))
+            .fg(theme.title)
+            .bold(),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!("last and next {} days ", render_state.team.days))
+            .alignment(Alignment::Right)
+            .fg(theme.accent)
+            .bold(),
+        days_area,
+    );
+
+    if !app_state.data.contains_key(&app_state.today) {
+        render_loading(
+            frame,
+            theme,
+            content_area,
+            &mut render_state.live_scores.throbber_state,
+        );
+        return;
+    }
+
+    let team_matches = app_state.team_matches(team_id);
+    let mut block = Block::bordered()
+        .border_set(symbols::border::DOUBLE)
+        .border_style(theme.border)
+        .title(form_line(theme, &team_matches.form(team_id, FORM_LENGTH)));
+    if !app_state.is_range_loaded(first, last) {
+        block = block.title_bottom(
+            Line::from(" loading more days... ")
+                .right_aligned()
+                .italic(),
+        );
+    }
+
+    if team_matches.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No matches found")
+                .italic()
+                .alignment(Alignment::Center)
+                .block(block),
+            content_area,
+        );
+        return;
+    }
+
+    // alternate row colours restart for each heading, as they do in the all live view
+    let mut idx = 0;
+    let rows = app_state
+        .team_rows(team_id)
+        .into_iter()
+        .map(|row| match row {
+            TeamRow::Heading(heading) => {
+                idx = 0;
+                Row::new(vec![Cell::new(heading)]).style(Style::new().fg(theme.accent).bold())
+            }
+            TeamRow::Match(match_data) => {
+                check_for_anomalies(&match_data, &mut render_state.anomalies);
+                let row = build_row(app_state, idx, team_id, &match_data);
+                idx += 1;
+                row
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(11),
+            Constraint::Length(10),
+            Constraint::Percentage(30),
+            Constraint::Length(25),
+            Constraint::Percentage(30),
+            Constraint::Length(2),
+            Constraint::Percentage(40),
+        ],
+    )
+    .row_highlight_style(theme.selected_row_style());
+
+    frame.render_stateful_widget(
+        table.block(block),
+        content_area,
+        &mut render_state.team.table_state,
+    );
+}
+
+/// the most recent results first, e.g. `Form W D L`
+fn form_line(theme: &Theme, form: &[MatchResult]) -> Line<'static> {
+    let mut spans = vec![Span::raw(" Form ").fg(theme.title).bold()];
+    if form.is_empty() {
+        spans.push(Span::raw("no results ").italic());
+    }
+    spans.extend(form.iter().map(|result| {
+        let (letter, color) = result_letter(theme, *result);
+        Span::raw(format!("{letter} ")).fg(color).bold()
+    }));
+    Line::from(spans)
+}
+
+fn build_row<'a>(app_state: &State, idx: usize, team_id: &TeamId, match_data: &Match) -> Row<'a> {
+    let theme = &app_state.theme;
+    let (text_color, row_color) = theme.row_colors(idx);
+
+    let status_style = if match_data.status.is_disrupted() {
+        Style::new().fg(theme.warning).bold()
+    } else if match_data.is_live() {
+        Style::new().fg(theme.live).bold()
+    } else {
+        Style::new().fg(text_color)
+    };
+    let score = if match_data.is_live() || match_data.is_finished() {
+        format_score(&match_data.score_summary())
+    } else {
+        "v".to_string()
+    };
+
+    // the team's own name is bold so it's easy to tell if they were home or away
+    let team_cell = |team: &Team, alignment| {
+        let name = team.name.clone().unwrap_or("tbc".into());
+        let text = Text::from(name).alignment(alignment).fg(text_color);
+        if team.id.as_ref() == Some(team_id) {
+            Cell::new(text.bold())
+        } else {
+            Cell::new(text)
+        }
+    };
+
+    let result = match match_data.result_for(team_id) {
+        Some(result) => {
+            let (letter, color) = result_letter(theme, result);
+            Cell::new(Text::from(letter).fg(color).bold())
+        }
+        None => Cell::new(""),
+    };
+
+    Row::new(vec![
+        Cell::new(
+            app_state
+                .clock
+                .date_of(match_data.date)
+                .format("%a %d %b")
+                .to_string(),
+        ),
+        Cell::new(Text::from(status_label(match_data, &app_state.clock)).style(status_style)),
+        team_cell(&match_data.home, Alignment::Right),
+        Cell::new(Text::from(score).alignment(Alignment::Center).bold()),
+        team_cell(&match_data.away, Alignment::Left),
+        result,
+        Cell::new(Text::from(match_data.comp.name.clone()).italic()),
+    ])
+    .style(Style::new().fg(text_color).bg(row_color))
+}
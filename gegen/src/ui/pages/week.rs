@@ -0,0 +1,228 @@
+use chrono::{Days, NaiveDate};
+use gegen_data::types::{Match, Team};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+};
+
+use crate::{PageRenderStates, State, state::WEEK_LENGTH, ui::theme::Theme};
+
+use super::shared::status_label;
+
+/// below this width per day the days are stacked instead of side by side
+const MIN_DAY_WIDTH: u16 = 22;
+
+/// The matches of one day, `None` while the day is loading
+type DayMatches = (NaiveDate, Option<Vec<Match>>);
+
+pub(crate) fn draw(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    start: &NaiveDate,
+    competition_name: &str,
+) {
+    let theme = &app_state.theme;
+    let last = start
+        .checked_add_days(Days::new(WEEK_LENGTH - 1))
+        .unwrap_or(*start);
+    let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
+    let [header_area, content_area] = vertical.areas(frame.area());
+
+    let [title_area, competition_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(header_area);
+    frame.render_widget(
+        Paragraph::new(format!(
+            " {} - {}",
+            start.format("%a %d %b"),
+            last.format("%a %d %b %Y")
+        ))
+        .fg(theme.title)
+        .bold(),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(format!("{competition_name} "))
+            .alignment(Alignment::Right)
+            .fg(theme.accent)
+            .bold(),
+        competition_area,
+    );
+
+    let days = start
+        .iter_days()
+        .take(WEEK_LENGTH as usize)
+        .map(|date| {
+            let matches = app_state.grouped_data_for(date).map(|grouped_data| {
+                grouped_data
+                    .into_iter()
+                    .find(|(name, _)| name == competition_name)
+                    .map(|(_, matches)| matches)
+                    .unwrap_or_default()
+            });
+            (date, matches)
+        })
+        .collect::<Vec<DayMatches>>();
+
+    if content_area.width >= MIN_DAY_WIDTH * WEEK_LENGTH as u16 {
+        draw_columns(frame, app_state, render_state, &days, content_area);
+    } else {
+        draw_stacked(frame, app_state, render_state, &days, content_area);
+    }
+}
+
+/// a column for each day with the kick off or status above the teams of each match
+fn draw_columns(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    days: &[DayMatches],
+    area: Rect,
+) {
+    let theme = &app_state.theme;
+    let columns =
+        Layout::horizontal([Constraint::Ratio(1, WEEK_LENGTH as u32); WEEK_LENGTH as usize])
+            .split(area);
+
+    let day_lines = days
+        .iter()
+        .zip(columns.iter())
+        .map(|((_, matches), column)| {
+            // the border takes a column on each side
+            let width = usize::from(column.width.saturating_sub(2));
+            match matches {
+                None => vec![Line::from("loading...").italic()],
+                Some(matches) if matches.is_empty() => {
+                    vec![Line::from("no matches").italic().dim()]
+                }
+                Some(matches) => matches
+                    .iter()
+                    .flat_map(|match_data| {
+                        let score = match_data.current_score();
+                        [
+                            Line::from(status_span(app_state, match_data)),
+                            team_line(&match_data.home, score.map(|score| score.home), width),
+                            team_line(&match_data.away, score.map(|score| score.away), width),
+                            Line::raw(""),
+                        ]
+                    })
+                    .collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let scroll = clamp_scroll(
+        render_state,
+        day_lines.iter().map(Vec::len).max().unwrap_or_default(),
+    );
+
+    for (((date, _), lines), column) in days.iter().zip(day_lines).zip(columns.iter()) {
+        let block = Block::bordered()
+            .border_set(symbols::border::DOUBLE)
+            .border_style(theme.border)
+            .title(day_title(theme, *date, app_state.today));
+        frame.render_widget(
+            Paragraph::new(lines).block(block).scroll((scroll, 0)),
+            *column,
+        );
+    }
+}
+
+/// one list with a header for each day and a line for each match, for narrow terminals
+fn draw_stacked(
+    frame: &mut Frame,
+    app_state: &State,
+    render_state: &mut PageRenderStates,
+    days: &[DayMatches],
+    area: Rect,
+) {
+    let theme = &app_state.theme;
+    let mut lines = Vec::new();
+
+    for (date, matches) in days {
+        lines.push(day_title(theme, *date, app_state.today));
+        match matches {
+            None => lines.push(Line::from("loading...").italic()),
+            Some(matches) if matches.is_empty() => {
+                lines.push(Line::from("no matches").italic().dim())
+            }
+            Some(matches) => lines.extend(matches.iter().map(|match_data| {
+                let teams = match match_data.current_score() {
+                    Some(score) => format!(
+                        "{} {} - {} {}",
+                        team_name(&match_data.home),
+                        score.home,
+                        score.away,
+                        team_name(&match_data.away)
+                    ),
+                    None => format!(
+                        "{} v {}",
+                        team_name(&match_data.home),
+                        team_name(&match_data.away)
+                    ),
+                };
+                let mut status = status_span(app_state, match_data);
+                status.content = format!("{:<10}", status.content).into();
+                Line::from(vec![status, Span::raw(teams)])
+            })),
+        }
+        lines.push(Line::raw(""));
+    }
+
+    let scroll = clamp_scroll(render_state, lines.len());
+    let block = Block::bordered()
+        .border_set(symbols::border::DOUBLE)
+        .border_style(theme.border);
+    frame.render_widget(Paragraph::new(lines).block(block).scroll((scroll, 0)), area);
+}
+
+/// stops the page scrolling past the last line
+fn clamp_scroll(render_state: &mut PageRenderStates, line_count: usize) -> u16 {
+    let max_scroll = u16::try_from(line_count.saturating_sub(1)).unwrap_or(u16::MAX);
+    render_state.week.scroll = render_state.week.scroll.min(max_scroll);
+    render_state.week.scroll
+}
+
+fn day_title(theme: &Theme, date: NaiveDate, today: NaiveDate) -> Line<'static> {
+    if date == today {
+        Line::from(format!(" Today {} ", date.format("%a %d %b")))
+            .fg(theme.accent)
+            .bold()
+    } else {
+        Line::from(format!(" {} ", date.format("%a %d %b")))
+            .fg(theme.title)
+            .bold()
+    }
+}
+
+fn status_span(app_state: &State, match_data: &Match) -> Span<'static> {
+    let theme = &app_state.theme;
+    let label = status_label(match_data, &app_state.clock);
+    if match_data.status.is_disrupted() {
+        Span::raw(label).fg(theme.warning).bold()
+    } else if match_data.is_live() {
+        Span::raw(label).fg(theme.live).bold()
+    } else {
+        Span::raw(label).dim()
+    }
+}
+
+fn team_name(team: &Team) -> &str {
+    team.name.as_deref().unwrap_or("tbc")
+}
+
+/// the team name, truncated to fit, with its goals on the right
+fn team_line(team: &Team, goals: Option<u8>, width: usize) -> Line<'static> {
+    let goals = goals.map(|goals| goals.to_string()).unwrap_or_default();
+    let name_width = width.saturating_sub(goals.len() + 1);
+    let name = team_name(team).chars().take(name_width).collect::<String>();
+    Line::from(vec![
+        Span::raw(format!("{name:<name_width$} ")),
+        Span::styled(goals, Style::new().bold()),
+    ])
+}
@@ -0,0 +1,303 @@
+use std::cmp::Reverse;
+
+use chrono::NaiveDate;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use fuzzy_matcher::{FuzzyMatcher, skim::SkimMatcherV2};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, List, ListState, Paragraph},
+};
+
+use crate::{
+    PageRenderStates,
+    state::{FAVOURITES_TAB, MatchesView, State},
+};
+
+use super::{
+    keymap::{Action, KeyScope},
+    popup_area,
+};
+
+const MAX_RESULTS: usize = 50;
+
+/// The query typed into the search pop up and the highlighted result
+#[derive(Debug, Default)]
+pub(crate) struct SearchState {
+    pub(crate) query: String,
+    pub(crate) selected: usize,
+    /// the results for `searched`, the query and data version they were worked out from
+    results: Vec<SearchResult>,
+    searched: Option<(String, usize)>,
+}
+
+impl SearchState {
+    /// Searches for the query again if it has changed or more data has arrived since the results
+    /// were worked out
+    fn refresh_results(&mut self, app_state: &State) {
+        let data_version = app_state.data_version();
+        let is_stale = self
+            .searched
+            .as_ref()
+            .is_none_or(|(query, version)| *query != self.query || *version != data_version);
+        if is_stale {
+            self.results = search(app_state, &self.query);
+            self.searched = Some((self.query.clone(), data_version));
+            self.selected = self.selected.min(self.results.len().saturating_sub(1));
+        }
+    }
+}
+
+/// A competition tab, or a match within it, that matched the query
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SearchResult {
+    pub(crate) date: NaiveDate,
+    pub(crate) tab: usize,
+    /// `None` for competitions, which jump to the tab without selecting a match
+    pub(crate) row: Option<usize>,
+    pub(crate) label: String,
+    score: i64,
+}
+
+/// Fuzzy matches team names and competition names, which include the country, across every date
+/// that has been loaded. Results on the current date are listed first when scores are equal
+pub(crate) fn search(app_state: &State, query: &str) -> Vec<SearchResult> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let matcher = SkimMatcherV2::default().ignore_case();
+    let current_date = app_state.current_date();
+    let mut results = Vec::new();
+
+    for date in app_state.loaded_dates() {
+        let Some(grouped_data) = app_state.grouped_data_for(date) else {
+            continue;
+        };
+
+        let date_prefix = if date == current_date {
+            String::new()
+        } else {
+            format!("{} · ", date.format("%a %d %b"))
+        };
+
+        for (tab, (competition_name, matches)) in grouped_data.iter().enumerate() {
+            // the same matches are in their competition's tab
+            if competition_name == FAVOURITES_TAB {
+                continue;
+            }
+
+            if let Some(score) = matcher.fuzzy_match(competition_name, query) {
+                results.push(SearchResult {
+                    date,
+                    tab,
+                    row: None,
+                    label: match matches.len() {
+                        1 => format!("{date_prefix}{competition_name} (1 match)"),
+                        n => format!("{date_prefix}{competition_name} ({n} matches)"),
+                    },
+                    score,
+                });
+            }
+
+            for (row, match_data) in matches.iter().enumerate() {
+                let score = [&match_data.home.name, &match_data.away.name]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|team_name| matcher.fuzzy_match(team_name, query))
+                    .max();
+
+                if let Some(score) = score {
+                    results.push(SearchResult {
+                        date,
+                        tab,
+                        row: Some(row),
+                        label: format!(
+                            "{date_prefix}{} v {} · {competition_name}",
+                            match_data.home.name.as_deref().unwrap_or("tbc"),
+                            match_data.away.name.as_deref().unwrap_or("tbc"),
+                        ),
+                        score,
+                    });
+                }
+            }
+        }
+    }
+
+    results.sort_by_key(|result| (Reverse(result.score), result.date != current_date));
+    results.truncate(MAX_RESULTS);
+    results
+}
+
+/// Handles a key press while the search pop up is open, keys that aren't bound to a search
+/// action are typed into the query so that letters bound to actions on the page can be typed
+pub(crate) fn process_key(
+    key: KeyEvent,
+    app_state: &mut State,
+    page_states: &mut PageRenderStates,
+) {
+    let Some(search_state) = &mut page_states.search else {
+        return;
+    };
+
+    match app_state.keymap.action(KeyScope::Search, key) {
+        Some(Action::Quit) => app_state.should_quit = true,
+        Some(Action::Close) => page_states.search = None,
+        Some(Action::Confirm) => {
+            search_state.refresh_results(app_state);
+            if let Some(result) = search_state.results.get(search_state.selected).cloned() {
+                jump_to_result(&result, app_state, page_states);
+            }
+            page_states.search = None;
+        }
+        Some(Action::PreviousResult) => {
+            search_state.selected = search_state.selected.saturating_sub(1);
+        }
+        Some(Action::NextResult) => {
+            search_state.refresh_results(app_state);
+            let last = search_state.results.len().saturating_sub(1);
+            search_state.selected = search_state.selected.saturating_add(1).min(last);
+        }
+        Some(Action::DeleteCharacter) => {
+            search_state.query.pop();
+            search_state.selected = 0;
+        }
+        Some(_) => (),
+        None => {
+            if let (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) =
+                (key.code, key.modifiers)
+            {
+                search_state.query.push(c);
+                search_state.selected = 0;
+            }
+        }
+    }
+}
+
+fn jump_to_result(
+    result: &SearchResult,
+    app_state: &mut State,
+    page_states: &mut PageRenderStates,
+) {
+    // the tab and row are from the date's competitions, not all live or the rolling window
+    app_state.go_to_date(result.date, page_states);
+    page_states.live_scores.view = MatchesView::Competitions;
+    page_states.live_scores.selected_tab = result.tab;
+    page_states.live_scores.table_state.select(result.row);
+}
+
+pub(crate) fn draw_search_pop_up(
+    frame: &mut Frame,
+    app_state: &State,
+    search_state: &mut SearchState,
+) {
+    search_state.refresh_results(app_state);
+
+    let block = Block::bordered()
+        .title("Search")
+        .title_style(Style::new().fg(app_state.theme.popup_title));
+    let area = popup_area(frame.area(), 60, 60);
+    frame.render_widget(Clear, area);
+
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [query_area, results_area] =
+        Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).areas(inner_area);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            "/ ".bold(),
+            search_state.query.clone().into(),
+            "▏".slow_blink(),
+        ])),
+        query_area,
+    );
+
+    if search_state.query.trim().is_empty() {
+        frame.render_widget(
+            Paragraph::new("search for teams, competitions and countries").italic(),
+            results_area,
+        );
+        return;
+    }
+
+    if search_state.results.is_empty() {
+        frame.render_widget(Paragraph::new("no matches").italic(), results_area);
+        return;
+    }
+
+    let list = List::new(
+        search_state
+            .results
+            .iter()
+            .map(|result| result.label.as_str()),
+    )
+    .highlight_style(app_state.theme.selected_row_style());
+    let mut list_state = ListState::default().with_selected(Some(search_state.selected));
+    frame.render_stateful_widget(list, results_area, &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use gegen_data::types::MatchId;
+
+    use super::*;
+    use crate::{
+        state::Page,
+        test_utils::{build_fixture, build_state, insert_matches, match_day},
+    };
+
+    #[test]
+    fn test_jump_to_result() {
+        let mut app_state = build_state();
+        let mut other_match = build_fixture("other", "England", "Premier League", 0);
+        other_match.home.name = Some("Arsenal".into());
+        let mut searched_match = build_fixture("searched", "Spain", "LaLiga", 2);
+        searched_match.home.name = Some("Sevilla".into());
+        insert_matches(&app_state, match_day(), vec![other_match, searched_match]);
+
+        // the jump has to leave the all live view and the rolling window
+        let mut page_states = PageRenderStates::default();
+        page_states.live_scores.view = MatchesView::AllLive;
+        app_state.rolling_window = true;
+        app_state.current_page = Page::Matches(match_day().succ_opt().unwrap());
+
+        let results = search(&app_state, "sevilla");
+        jump_to_result(&results[0], &mut app_state, &mut page_states);
+
+        assert_eq!(
+            app_state
+                .current_match(&page_states)
+                .map(|match_data| match_data.id),
+            Some(MatchId::new("searched"))
+        );
+    }
+
+    #[test]
+    fn test_results_are_searched_again_when_data_arrives() {
+        let app_state = build_state();
+        insert_matches(&app_state, match_day(), Vec::new());
+        let mut search_state = SearchState {
+            query: "sevilla".into(),
+            ..Default::default()
+        };
+        search_state.refresh_results(&app_state);
+        assert!(search_state.results.is_empty());
+
+        let mut sevilla_match = build_fixture("sevilla", "Spain", "LaLiga", 0);
+        sevilla_match.home.name = Some("Sevilla".into());
+        insert_matches(&app_state, match_day(), vec![sevilla_match]);
+        search_state.refresh_results(&app_state);
+        assert!(search_state.results.is_empty());
+
+        app_state.data_version.fetch_add(1, Ordering::Relaxed);
+        search_state.refresh_results(&app_state);
+        assert_eq!(search_state.results.len(), 1);
+    }
+}
@@ -0,0 +1,286 @@
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use serde::Deserialize;
+
+/// Built in themes, selected with `preset` in the `[theme]` section of the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ThemePreset {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+    /// no colours at all, selections are shown with reversed text instead
+    Monochrome,
+}
+
+/// The colours used by every page, see [`ThemePreset`] for the built in themes
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Theme {
+    pub(crate) border: Color,
+    pub(crate) title: Color,
+    pub(crate) accent: Color,
+    pub(crate) tab_highlight_fg: Color,
+    pub(crate) tab_highlight_bg: Color,
+    pub(crate) row_fg: Color,
+    pub(crate) row_bg: Color,
+    pub(crate) alt_row_fg: Color,
+    pub(crate) alt_row_bg: Color,
+    pub(crate) selected_row_fg: Color,
+    pub(crate) selected_row_bg: Color,
+    /// matches that are being played or are at a break
+    pub(crate) live: Color,
+    /// postponed, suspended, abandoned and cancelled matches
+    pub(crate) warning: Color,
+    /// goals and shootout winners
+    pub(crate) highlight: Color,
+    /// wins, draws and losses in the form guide on the team page
+    pub(crate) win: Color,
+    pub(crate) draw: Color,
+    pub(crate) loss: Color,
+    pub(crate) popup_title: Color,
+    pub(crate) throbber: Color,
+    pub(crate) throbber_label: Color,
+    /// added to the selected tab and row so they stand out even without colours
+    pub(crate) selection_modifier: Modifier,
+}
+
+/// Per colour overrides applied on top of a preset, see the `[theme.colors]` section of the
+/// config file
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct ThemeColors {
+    pub(crate) border: Option<Color>,
+    pub(crate) title: Option<Color>,
+    pub(crate) accent: Option<Color>,
+    pub(crate) tab_highlight_fg: Option<Color>,
+    pub(crate) tab_highlight_bg: Option<Color>,
+    pub(crate) row_fg: Option<Color>,
+    pub(crate) row_bg: Option<Color>,
+    pub(crate) alt_row_fg: Option<Color>,
+    pub(crate) alt_row_bg: Option<Color>,
+    pub(crate) selected_row_fg: Option<Color>,
+    pub(crate) selected_row_bg: Option<Color>,
+    pub(crate) live: Option<Color>,
+    pub(crate) warning: Option<Color>,
+    pub(crate) highlight: Option<Color>,
+    pub(crate) win: Option<Color>,
+    pub(crate) draw: Option<Color>,
+    pub(crate) loss: Option<Color>,
+    pub(crate) popup_title: Option<Color>,
+    pub(crate) throbber: Option<Color>,
+    pub(crate) throbber_label: Option<Color>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::preset(ThemePreset::default())
+    }
+}
+
+impl Theme {
+    /// Builds the theme from the config file. When no preset is set and `no_color` is true the
+    /// monochrome preset is used, an explicit preset in the config file takes precedence as
+    /// described at <https://no-color.org>
+    pub(crate) fn new(preset: Option<ThemePreset>, colors: &ThemeColors, no_color: bool) -> Self {
+        let preset = preset.unwrap_or(if no_color {
+            ThemePreset::Monochrome
+        } else {
+            ThemePreset::Dark
+        });
+
+        Self::preset(preset).with_overrides(colors)
+    }
+
+    pub(crate) fn preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Dark => Self {
+                border: Color::Green,
+                title: Color::LightGreen,
+                accent: Color::Magenta,
+                tab_highlight_fg: Color::Magenta,
+                tab_highlight_bg: Color::Green,
+                row_fg: Color::White,
+                row_bg: Color::Black,
+                alt_row_fg: Color::Black,
+                alt_row_bg: Color::Green,
+                selected_row_fg: Color::LightMagenta,
+                selected_row_bg: Color::White,
+                live: Color::Red,
+                warning: Color::Yellow,
+                highlight: Color::Yellow,
+                win: Color::LightGreen,
+                draw: Color::Yellow,
+                loss: Color::Red,
+                popup_title: Color::Red,
+                throbber: Color::Magenta,
+                throbber_label: Color::Cyan,
+                selection_modifier: Modifier::empty(),
+            },
+            ThemePreset::Light => Self {
+                border: Color::Blue,
+                title: Color::Blue,
+                accent: Color::Magenta,
+                tab_highlight_fg: Color::White,
+                tab_highlight_bg: Color::Blue,
+                row_fg: Color::Black,
+                row_bg: Color::Reset,
+                alt_row_fg: Color::Black,
+                alt_row_bg: Color::Rgb(225, 230, 240),
+                selected_row_fg: Color::White,
+                selected_row_bg: Color::Magenta,
+                live: Color::Red,
+                // yellow is unreadable on a light background
+                warning: Color::Rgb(175, 95, 0),
+                highlight: Color::Rgb(175, 95, 0),
+                win: Color::Rgb(0, 135, 0),
+                draw: Color::Rgb(175, 95, 0),
+                loss: Color::Red,
+                popup_title: Color::Red,
+                throbber: Color::Magenta,
+                throbber_label: Color::Blue,
+                selection_modifier: Modifier::empty(),
+            },
+            ThemePreset::HighContrast => Self {
+                border: Color::White,
+                title: Color::LightYellow,
+                accent: Color::LightCyan,
+                tab_highlight_fg: Color::Black,
+                tab_highlight_bg: Color::LightYellow,
+                row_fg: Color::White,
+                row_bg: Color::Black,
+                alt_row_fg: Color::LightYellow,
+                alt_row_bg: Color::Black,
+                selected_row_fg: Color::Black,
+                selected_row_bg: Color::LightCyan,
+                live: Color::LightRed,
+                warning: Color::LightYellow,
+                highlight: Color::LightCyan,
+                win: Color::LightGreen,
+                draw: Color::LightYellow,
+                loss: Color::LightRed,
+                popup_title: Color::LightYellow,
+                throbber: Color::White,
+                throbber_label: Color::White,
+                selection_modifier: Modifier::BOLD,
+            },
+            ThemePreset::Monochrome => Self {
+                border: Color::Reset,
+                title: Color::Reset,
+                accent: Color::Reset,
+                tab_highlight_fg: Color::Reset,
+                tab_highlight_bg: Color::Reset,
+                row_fg: Color::Reset,
+                row_bg: Color::Reset,
+                alt_row_fg: Color::Reset,
+                alt_row_bg: Color::Reset,
+                selected_row_fg: Color::Reset,
+                selected_row_bg: Color::Reset,
+                live: Color::Reset,
+                warning: Color::Reset,
+                highlight: Color::Reset,
+                win: Color::Reset,
+                draw: Color::Reset,
+                loss: Color::Reset,
+                popup_title: Color::Reset,
+                throbber: Color::Reset,
+                throbber_label: Color::Reset,
+                selection_modifier: Modifier::REVERSED,
+            },
+        }
+    }
+
+    pub(crate) fn with_overrides(mut self, colors: &ThemeColors) -> Self {
+        macro_rules! apply_overrides {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if let Some(color) = colors.$field {
+                        self.$field = color;
+                    }
+                )*
+            };
+        }
+
+        apply_overrides!(
+            border,
+            title,
+            accent,
+            tab_highlight_fg,
+            tab_highlight_bg,
+            row_fg,
+            row_bg,
+            alt_row_fg,
+            alt_row_bg,
+            selected_row_fg,
+            selected_row_bg,
+            live,
+            warning,
+            highlight,
+            win,
+            draw,
+            loss,
+            popup_title,
+            throbber,
+            throbber_label,
+        );
+
+        self
+    }
+
+    /// foreground and background colour of a table row, alternating so rows are easy to follow
+    pub(crate) fn row_colors(&self, idx: usize) -> (Color, Color) {
+        match idx % 2 {
+            0 => (self.row_fg, self.row_bg),
+            _ => (self.alt_row_fg, self.alt_row_bg),
+        }
+    }
+
+    pub(crate) fn tab_highlight_style(&self) -> Style {
+        Style::new()
+            .fg(self.tab_highlight_fg)
+            .bg(self.tab_highlight_bg)
+            .bold()
+            .add_modifier(self.selection_modifier)
+    }
+
+    pub(crate) fn selected_row_style(&self) -> Style {
+        Style::new()
+            .fg(self.selected_row_fg)
+            .bg(self.selected_row_bg)
+            .add_modifier(self.selection_modifier)
+    }
+}
+
+/// whether the `NO_COLOR` environment variable is set to a non empty value
+pub(crate) fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_color_only_applies_without_a_preset() {
+        let colors = ThemeColors::default();
+        assert_eq!(
+            Theme::new(None, &colors, true),
+            Theme::preset(ThemePreset::Monochrome)
+        );
+        assert_eq!(
+            Theme::new(Some(ThemePreset::Light), &colors, true),
+            Theme::preset(ThemePreset::Light)
+        );
+        assert_eq!(Theme::new(None, &colors, false), Theme::default());
+    }
+
+    #[test]
+    fn test_overrides() {
+        let colors = ThemeColors {
+            live: Some(Color::Rgb(255, 95, 0)),
+            ..Default::default()
+        };
+        let theme = Theme::new(Some(ThemePreset::Dark), &colors, false);
+        assert_eq!(theme.live, Color::Rgb(255, 95, 0));
+        assert_eq!(theme.border, Theme::default().border);
+    }
+}